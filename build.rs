@@ -30,5 +30,8 @@ fn main() {
     */
     cc::Build::new()
         .file("src/allocator.c")
-        .compile("allocator")
+        .compile("allocator");
+
+    /* cc only watches environment variables, so rebuild when the C source changes */
+    println!("cargo:rerun-if-changed=src/allocator.c");
 }
@@ -47,3 +47,25 @@ void c_global_deallocator(void* ptr) {
     /* Rust allocator manages NULL check */
     free(ptr);
 }
+
+void* c_global_aligned_allocator(arch_type bytes, arch_type align) {
+    /* Used for alignments stricter than malloc() guarantees */
+#if defined(_WIN32)
+    return _aligned_malloc(bytes, align);
+#else
+    void* ptr = NULL;
+    if (posix_memalign(&ptr, align, bytes) != 0) {
+        return NULL;
+    }
+    return ptr;
+#endif
+}
+
+void c_global_aligned_deallocator(void* ptr) {
+    /* Memory from _aligned_malloc() must be released with _aligned_free() */
+#if defined(_WIN32)
+    _aligned_free(ptr);
+#else
+    free(ptr);
+#endif
+}
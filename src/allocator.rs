@@ -43,21 +43,35 @@ mod c_ffi {
 extern "C" {
     fn c_global_allocator(bytes: arch_type) -> *mut c_void;
     fn c_global_deallocator(ptr: *mut u8) -> c_void;
+    fn c_global_aligned_allocator(bytes: arch_type, align: arch_type) -> *mut c_void;
+    fn c_global_aligned_deallocator(ptr: *mut u8) -> c_void;
 }
 
+/* Alignment malloc() guarantees, stricter alignments go through the aligned C functions */
+const MALLOC_ALIGN: usize = 2 * std::mem::size_of::<usize>();
+
 pub(self) struct C_GLOBAL_ALLOCATOR;
 
 unsafe impl GlobalAlloc for C_GLOBAL_ALLOCATOR {
     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
-        let ptr: *mut u8 = c_global_allocator(layout.size() as arch_type) as *mut u8;    
+        let ptr: *mut u8 = if layout.align() > MALLOC_ALIGN {
+            c_global_aligned_allocator(layout.size() as arch_type, layout.align() as arch_type) as *mut u8
+        } else {
+            c_global_allocator(layout.size() as arch_type) as *mut u8
+        };
         if ptr.is_null() {
             handle_alloc_error(layout);
         }
         ptr
     }
 
-    unsafe fn dealloc(&self, ptr: *mut u8, _layout: Layout) {
-        if !ptr.is_null() {
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ptr.is_null() {
+            return;
+        }
+        if layout.align() > MALLOC_ALIGN {
+            c_global_aligned_deallocator(ptr);
+        } else {
             c_global_deallocator(ptr);
         }
     }
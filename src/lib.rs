@@ -43,6 +43,8 @@
 //!
 //! - **MutRawPtr**: Offers safe operations on `*mut T` pointers, supporting mutable access and memory management.
 //!
+//! - **MutRawPtrOwned**: Owns a C-allocated block of non-`Copy` elements, giving access by reference and dropping every element before deallocation.
+//!
 //! ## Usage
 //!
 //! ```rust
@@ -57,7 +59,7 @@
 //!
 //!     let ptr: *mut i32 = unsafe { c_ptr() };
 //!
-//!     let safeptr = MutRawPtr::new(ptr, 1, 1);
+//!     let safeptr = MutRawPtr::new(ptr, 1, 0);
 //!
 //!     safeptr.write_ptr(14).unwrap();
 //!
@@ -68,15 +70,18 @@
 //!     let alloc: *const i32 = unsafe { 
 //!         std::alloc::alloc(std::alloc::Layout::from_size_align(20, 4).unwrap()) as *const i32 
 //!     };
-//!     let mut ptr: ConstRawPtr<i32> = ConstRawPtr::new(alloc, 5, 1);
+//!     let mut ptr: ConstRawPtr<i32> = ConstRawPtr::new(alloc, 5, 0);
+//!
+//!     // new never frees memory it wraps, so opt in to freeing it on drop
+//!     unsafe { ptr.set_owned(true) };
 //!
 //!     ptr.change_offset(4).unwrap();
 //!
 //!     println!("{} : {}", ptr.unwrap().unwrap(), ptr.memory_address());
 //! 
 //!     // Example: Allocate data using c_malloc
-//!     let alloc: *const i32 = ConstRawPtr::c_malloc(1).unwrap();
-//!     let _: ConstRawPtr<i32> = ConstRawPtr::new(t, 1, 1);
+//!     // The returned pointer owns the allocation and frees it when dropped
+//!     let _: ConstRawPtr<i32> = unsafe { ConstRawPtr::c_malloc(1, 0).unwrap() };
 //! }
 //! ```
 //!
@@ -88,13 +93,15 @@
 //!
 //! - **Dropping Pointers**: Manually dropping pointers can lead to undefined behavior if used afterward.
 //!
+//! - **Ownership**: Only pointers created by `c_malloc` free their memory on drop. Pointers created with `new` leave the memory to its owner (e.g. C) unless `set_owned(true)` is called.
+//!
 //! ## Installation
 //!
 //! Add the following to your `Cargo.toml`:
 //!
 //! ```toml
 //! [dependencies]
-//! box_raw_ptr = "2.0.2"
+//! box_raw_ptr = "3.0.0"
 //! ```
 //!
 //! ## Documentation
@@ -132,6 +139,9 @@ Note: ALL LIBRARY HEAP ALLOCATIONS MANAGED BY MALLOC AND FREE
 */
 mod allocator;
 
+#[cfg(feature = "serde")]
+extern crate serde;
+
 pub mod const_raw_ptr {
     use std::marker::{Copy, Send, Sync};
 
@@ -143,11 +153,15 @@ pub mod const_raw_ptr {
     /// Fields:
     /// - `ptr: *const T`: A raw constant pointer to the data.
     /// - `memory_length: usize`: The length of the memory block that `ptr` points to.
+    /// - `capacity: usize`: The number of elements the underlying allocation can hold.
     /// - `offset: usize`: The current position within the memory block.
+    /// - `owned: bool`: Whether the memory block is deallocated when the `ConstRawPtr` is dropped.
+    /// - `align: usize`: The alignment the memory block was allocated with.
     ///
     /// Notes:
     /// - `memory_length` is not zero-based indexed.
-    /// - `offset` is not zero-based indexed.
+    /// - `offset` is zero-based indexed, so it is within bounds while `offset < memory_length`.
+    /// - `memory_length` never exceeds `capacity`.
     ///
     /// # Safety
     ///
@@ -156,22 +170,28 @@ pub mod const_raw_ptr {
     pub struct ConstRawPtr<T> 
     where  T: Sized + Copy + Send + Sync
     {
-        ptr: *const T,
-        memory_length: usize,
-        offset: usize,
+        pub(crate) ptr: *const T,
+        pub(crate) memory_length: usize,
+        pub(crate) capacity: usize,
+        pub(crate) offset: usize,
+        pub(crate) owned: bool,
+        pub(crate) align: usize,
     }
 
     impl<T: Sized + Copy + Send + Sync> ConstRawPtr<T> {
-        /// Allocates memory for an array of `memory_length` elements of type `T` and returns a constant raw pointer to the allocated memory.
+        /// Allocates memory for an array of `memory_length` elements of type `T` and returns an owning `ConstRawPtr` to it.
+        ///
+        /// The returned pointer owns the allocation, so the memory block is deallocated when it is dropped.
         ///
         /// # Parameters
         ///
         /// - `memory_length`: The number of elements of type `T` to allocate memory for. Must be greater than 0.
+        /// - `offset`: The initial offset of the pointer. Must be within the bounds of `memory_length`.
         ///
         /// # Returns
         ///
-        /// - `Some(*const T)`: A constant raw pointer to the allocated memory if successful.
-        /// - `None`: If `memory_length` is 0 or less.
+        /// - `Some(ConstRawPtr<T>)`: An owning pointer to the allocated memory if successful.
+        /// - `None`: If `memory_length` is 0, the offset is out of bounds, or the allocation failed.
         ///
         /// # Panics
         ///
@@ -181,16 +201,16 @@ pub mod const_raw_ptr {
         ///
         /// # Safety
         ///
-        /// This function is unsafe because it performs a raw allocation and returns a raw pointer. The caller is responsible for ensuring that the memory is properly initialized and freed.
+        /// The allocated memory is not initialized. The caller must write every element before reading it, including 
+        /// through safe methods such as `access`, `as_slice` or `iter`.
         ///
         /// # Example
         ///
         /// ```rust
-        /// let alloc: *const i32 = ConstRawPtr::c_malloc(1).unwrap();
-        /// let _: ConstRawPtr<i32> = ConstRawPtr::new(t, 1, 1);
+        /// let _: ConstRawPtr<i32> = unsafe { ConstRawPtr::c_malloc(1, 0).unwrap() };
         /// ```
-        pub fn c_malloc(memory_length: usize) -> Option<*const T> {
-            if memory_length <= 0 {
+        pub unsafe fn c_malloc(memory_length: usize, offset: usize) -> Option<Self> {
+            if memory_length <= 0 || offset >= memory_length {
                 return None;
             }
 
@@ -200,15 +220,94 @@ pub mod const_raw_ptr {
 
             unsafe {
                 let alloc: *const T = std::alloc::alloc(layout) as *const T;
-                return Some(alloc);
+                if alloc.is_null() {
+                    return None;
+                }
+                #[cfg(feature = "debug-trace")]
+                super::debug_trace::record(alloc as usize, memory_length);
+                return Some(Self { ptr: alloc, memory_length, capacity: memory_length, offset, owned: true, align: std::mem::align_of::<T>() });
             } 
         }
 
+        /// Allocates memory for an array of `memory_length` zeroed elements of type `T` and returns an owning `ConstRawPtr` to it.
+        ///
+        /// # Safety
+        ///
+        /// Every element starts out as all-zero bytes, so this is only valid for types where an all-zero bit 
+        /// pattern is a valid value of `T` (e.g. integers and floats, but not references or `NonNull`).
+        ///
+        /// # Returns
+        ///
+        /// - `Some(ConstRawPtr<T>)`: An owning pointer to the zeroed memory if successful.
+        /// - `None`: If `memory_length` is 0, the offset is out of bounds, or the allocation failed.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// let zeroed: ConstRawPtr<u64> = unsafe { ConstRawPtr::c_calloc(10, 0).unwrap() };
+        /// ```
+        pub unsafe fn c_calloc(memory_length: usize, offset: usize) -> Option<Self> {
+            if memory_length == 0 || offset >= memory_length {
+                return None;
+            }
+            let layout: std::alloc::Layout = std::alloc::Layout::array::<T>(memory_length).ok()?;
+            let alloc: *const T = std::alloc::alloc_zeroed(layout) as *const T;
+            if alloc.is_null() {
+                return None;
+            }
+            #[cfg(feature = "debug-trace")]
+            super::debug_trace::record(alloc as usize, memory_length);
+            Some(Self { ptr: alloc, memory_length, capacity: memory_length, offset, owned: true, align: std::mem::align_of::<T>() })
+        }
+
+        /// Allocates memory for `memory_length` elements of type `T` aligned to `align` bytes, copies `data` into it, and 
+        /// returns an owning `ConstRawPtr` to it.
+        ///
+        /// This is useful for over-aligned buffers, such as cache-line aligned data for SIMD. The alignment is stored 
+        /// so the memory block is deallocated with a matching layout on drop.
+        ///
+        /// # Safety
+        ///
+        /// Only the first `data.len()` elements are initialized. The caller must write the rest before reading them.
+        ///
+        /// # Returns
+        ///
+        /// - `Some(ConstRawPtr<T>)`: An owning pointer to the aligned memory if successful.
+        /// - `None`: If `align` is not a power of two or is smaller than `align_of::<T>()`, `memory_length` is 0, 
+        ///   `data` is longer than `memory_length`, the offset is out of bounds, or the allocation failed.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// let simd: ConstRawPtr<f32> = unsafe { ConstRawPtr::c_malloc_aligned(vec![0.0; 16], 16, 0, 64).unwrap() };
+        /// ```
+        pub unsafe fn c_malloc_aligned(data: Vec<T>, memory_length: usize, offset: usize, align: usize) -> Option<Self> {
+            if !align.is_power_of_two() || align < std::mem::align_of::<T>() {
+                return None;
+            }
+            if memory_length == 0 || data.len() > memory_length || offset >= memory_length {
+                return None;
+            }
+            let size: usize = std::mem::size_of::<T>().checked_mul(memory_length)?;
+            let layout: std::alloc::Layout = std::alloc::Layout::from_size_align(size, align).ok()?;
+            let alloc: *const T = std::alloc::alloc(layout) as *const T;
+            if alloc.is_null() {
+                return None;
+            }
+            std::ptr::copy_nonoverlapping(data.as_ptr(), alloc as *mut T, data.len());
+            #[cfg(feature = "debug-trace")]
+            super::debug_trace::record(alloc as usize, memory_length);
+            Some(Self { ptr: alloc, memory_length, capacity: memory_length, offset, owned: true, align })
+        }
+
         /// Creates a new `ConstRawPtr` with the given pointer, memory length, and offset.
         /// 
         /// This method ensures that the pointer is properly aligned and that the offset is within the bounds 
         /// of the allocated memory length.
         /// 
+        /// The returned pointer does not own the memory block, so it is never deallocated on drop. Call 
+        /// `set_owned(true)` if the memory was allocated by this library's allocator and should be freed on drop.
+        /// 
         /// # Panics
         /// 
         /// Panics if the pointer is not aligned to `T` or if the offset is not within the bounds of the memory length.
@@ -217,13 +316,59 @@ pub mod const_raw_ptr {
         /// 
         /// ```rust
         /// let alloc_ptr: *const i32 = ...; // Assume this is a properly allocated and aligned pointer either from C or using Rust's std::alloc::alloc and std::alloc::Layout otherwise it will panic.
-        /// let ptr = ConstRawPtr::new(alloc_ptr, 1, 1);
+        /// let ptr = ConstRawPtr::new(alloc_ptr, 1, 0);
         /// ```
         #[inline]
         pub fn new(ptr: *const T, memory_length: usize, offset: usize) -> Self {
-            assert!((ptr as usize) % std::mem::align_of::<T>() == 0, "box_raw_ptr Err: Memory Not Aligned");
-            assert!(offset <= memory_length && offset > 0, "box_raw_ptr Err: Offset Is Not Within Bounds");
-            Self { ptr, memory_length, offset, }
+            Self::try_new(ptr, memory_length, offset).unwrap_or_else(|err| panic!("{}", err))
+        }
+
+        /// Creates a new `ConstRawPtr` like `new`, returning an error instead of panicking.
+        /// 
+        /// This is useful for validating pointers received from C at runtime. The returned pointer does not own 
+        /// the memory block.
+        /// 
+        /// # Errors
+        /// 
+        /// - `BoxRawPtrError::Misaligned`: If the pointer is not aligned to `T`.
+        /// - `BoxRawPtrError::OutOfBounds`: If the offset is not within the bounds of the memory length.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let ptr = ConstRawPtr::try_new(alloc_ptr, 1, 0)?;
+        /// ```
+        pub fn try_new(ptr: *const T, memory_length: usize, offset: usize) -> Result<Self, super::error::BoxRawPtrError> {
+            if !(ptr as usize).is_multiple_of(std::mem::align_of::<T>()) {
+                return Err(super::error::BoxRawPtrError::Misaligned);
+            }
+            if offset >= memory_length {
+                return Err(super::error::BoxRawPtrError::OutOfBounds);
+            }
+            Ok(Self { ptr, memory_length, capacity: memory_length, offset, owned: false, align: std::mem::align_of::<T>() })
+        }
+
+        /// Creates a new `ConstRawPtr` with the given pointer, memory length, and offset, without requiring alignment.
+        /// 
+        /// This is meant for packed or deliberately unaligned C data. Elements of such a pointer must be read 
+        /// with `access_unaligned`; the aligned methods check alignment and return `None`. Mixing 
+        /// `new_unaligned` with `ref_const` is still undefined behavior since references require alignment.
+        /// The returned pointer does not own the memory block.
+        /// 
+        /// # Panics
+        /// 
+        /// Panics if the offset is not within the bounds of the memory length.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let packed_ptr: *const u32 = ...; // Assume this points into a packed C structure
+        /// let ptr = ConstRawPtr::new_unaligned(packed_ptr, 1, 0);
+        /// ```
+        #[inline]
+        pub fn new_unaligned(ptr: *const T, memory_length: usize, offset: usize) -> Self {
+            assert!(offset < memory_length, "box_raw_ptr Err: Offset Is Not Within Bounds");
+            Self { ptr, memory_length, capacity: memory_length, offset, owned: false, align: std::mem::align_of::<T>() }
         }
 
         /// Creates a new `ConstRawPtr` with a null pointer and zero memory length and offset.
@@ -237,7 +382,7 @@ pub mod const_raw_ptr {
         /// ```
         #[inline]
         pub fn nullptr() -> Self {
-            Self { ptr: std::ptr::null(), memory_length: 0, offset: 0 }
+            Self { ptr: std::ptr::null(), memory_length: 0, capacity: 0, offset: 0, owned: false, align: std::mem::align_of::<T>() }
         }
 
         /// Manually drops the `ConstRawPtr` instance.
@@ -270,7 +415,7 @@ pub mod const_raw_ptr {
         /// ```
         #[inline]
         pub fn check_bounds(&self) -> bool {
-            (1..=self.memory_length).contains(&self.offset)
+            self.offset < self.memory_length
         }
 
         /// Checks if the pointer is not null and properly aligned.
@@ -316,6 +461,20 @@ pub mod const_raw_ptr {
             self.memory_length
         }
 
+        /// Returns the current capacity.
+        /// 
+        /// This method provides the number of elements the allocation behind the pointer can hold, which is 
+        /// never less than the memory length.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let capacity = ptr.check_capacity();
+        /// ```
+        pub fn check_capacity(&self) -> usize {
+            self.capacity
+        }
+
         /// Changes the offset by a given index, if the resulting offset is within bounds.
         /// 
         /// This method allows you to move the pointer by a specified index within the memory block, 
@@ -327,24 +486,45 @@ pub mod const_raw_ptr {
         /// assert!(ptr.change_offset(2).is_some());
         /// ```
         pub fn change_offset(&mut self, index: isize) -> Option<()> {
-            if !self.check_ptr() {
-                return None;
-            }
-            let new_offset: isize = self.offset as isize + index;
-            if new_offset > 0 && new_offset <= self.memory_length as isize {
-                self.offset = new_offset as usize;
-                Some(())
-            } else {
-                None
-            }
+            self.try_change_offset(index).ok()
+        }
+
+        /// Moves the offset by `count` elements using checked arithmetic.
+        /// 
+        /// The new offset is validated against `0..memory_length` before anything is changed, and no pointer 
+        /// arithmetic is performed, so a pathological `count` such as `isize::MAX` returns `None` instead of 
+        /// overflowing. This is the same check `change_offset` performs.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the offset was moved.
+        /// - `None`: If the pointer is invalid or the resulting offset is out of bounds or overflows.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(ptr.checked_offset(isize::MAX).is_none());
+        /// ```
+        pub fn checked_offset(&mut self, count: isize) -> Option<()> {
+            self.try_change_offset(count).ok()
         }
 
         /// Changes the memory length, if the new length is valid.
         /// 
+        /// The new length may not exceed the capacity of the allocation, so the memory length can never outgrow 
+        /// the memory block that is deallocated on drop. Shrinking leaves the capacity, and therefore the layout 
+        /// used on drop, unchanged.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the memory length was changed.
+        /// - `None`: If `memory_length` is 0, exceeds the capacity, or the current offset would fall outside it.
+        /// 
         /// # Safety
         /// 
-        /// This function is unsafe because it directly modifies the memory length. Ensure that the new length is 
-        /// valid and that the memory block can accommodate the new length.
+        /// This function is unsafe because it directly modifies the memory length. The capacity must match the true 
+        /// allocation, which is not the case for a pointer adopted with the wrong length through `new`; correct 
+        /// such pointers with `with_corrected_length` first.
         /// 
         /// # Examples
         /// 
@@ -354,7 +534,7 @@ pub mod const_raw_ptr {
         /// }
         /// ```
         pub unsafe fn change_memory_length(&mut self, memory_length: usize) -> Option<()> {
-            if memory_length <= 0 || self.offset > memory_length || self.offset < memory_length {
+            if memory_length == 0 || memory_length > self.capacity || self.offset >= memory_length {
                 return None;
             }
 
@@ -496,521 +676,6619 @@ pub mod const_raw_ptr {
             std::mem::size_of::<T>()
         }
 
-        /// Casts the pointer to a `ConstRawPtr` of another type `U`.
+        /// Casts the pointer to a view of another type `U`.
+        /// 
+        /// The memory length, capacity, and offset are rescaled from elements of `T` to elements of `U`, so the 
+        /// view covers the same bytes. The offset points at the element of `U` containing the current element 
+        /// of `T`. The view borrows `self` and never deallocates, so the allocation is still freed only by `self`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(BorrowedConstRawPtr<U>)`: The cast view.
+        /// - `None`: If the pointer is null, is not aligned for `U`, `U` is zero-sized, or the memory block or 
+        ///   capacity is not a whole number of elements of `U`.
+        /// 
+        /// # Safety
         /// 
-        /// This method allows you to reinterpret the pointer as a different type, ensuring that the new type 
-        /// is compatible and properly aligned.
+        /// The bytes of the memory block must be initialized and form valid values of `U`, e.g. only `0` or `1` 
+        /// for `bool`.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// let new_ptr = ptr.cast_ptr::<f64>().unwrap();
+        /// let bytes = unsafe { ptr.cast_ptr::<u8>() }.unwrap();
         /// ```
-        pub fn cast_ptr<U: Sized + Copy + Send + Sync>(&self) -> Option<ConstRawPtr<U>> {
-            if !self.ptr.is_null() {
-                Some(ConstRawPtr {
-                     ptr: self.ptr as *const U, memory_length: self.memory_length, offset: self.offset
-                })
-            } else {
-                None
+        pub unsafe fn cast_ptr<U: Sized + Copy + Send + Sync>(&self) -> Option<BorrowedConstRawPtr<'_, U>> {
+            let size: usize = std::mem::size_of::<T>();
+            let new_size: usize = std::mem::size_of::<U>();
+            if self.ptr.is_null() || new_size == 0 || !(self.ptr as usize).is_multiple_of(std::mem::align_of::<U>()) {
+                return None;
             }
+            let bytes: usize = size.checked_mul(self.memory_length)?;
+            let capacity_bytes: usize = size.checked_mul(self.capacity)?;
+            if !bytes.is_multiple_of(new_size) || !capacity_bytes.is_multiple_of(new_size) {
+                return None;
+            }
+            Some(BorrowedConstRawPtr::new(ConstRawPtr {
+                ptr: self.ptr as *const U,
+                memory_length: bytes / new_size,
+                capacity: capacity_bytes / new_size,
+                offset: size * self.offset / new_size,
+                owned: false,
+                align: self.align,
+            }))
         }
-    }
-
-    impl<T: Sized + Copy + Send + Sync> Clone for ConstRawPtr<T> {
-        fn clone(&self) -> Self {
-            Self { ptr: self.ptr.clone(), memory_length: self.memory_length, offset: self.offset }
-        }
-    }
-
-    impl<T: Sized + Copy + Send + Sync> std::fmt::Debug for ConstRawPtr<T> {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            f.debug_struct("ConstRawPtr")
-                .field("ptr", &self.ptr)
-                .field("memory_length", &self.memory_length)
-                .field("offset", &self.offset)
-                .finish()
-        }
-    }
-
-    impl<T: Sized + Copy + Send + Sync> PartialEq for ConstRawPtr<T> {
-        fn eq(&self, other: &Self) -> bool {
-            self.ptr == other.ptr
-        }
-
-        fn ne(&self, other: &Self) -> bool {
-            self.ptr != other.ptr
-        }
-    }
 
-    impl<T: Sized + Copy + Send + Sync> Drop for ConstRawPtr<T> {
-        fn drop(&mut self) {
-            if self.check_ptr() {
+        /// Computes the dot product of two memory blocks.
+        /// 
+        /// This method multiplies corresponding elements over the shorter of the two memory lengths and sums 
+        /// the products in a single pass, starting from `T::default()`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let product = ptr.dot(&other).unwrap();
+        /// ```
+        pub fn dot(&self, other: &Self) -> Option<T>
+        where T: Default + std::ops::Add<Output = T> + std::ops::Mul<Output = T>
+        {
+            if !self.check_ptr() || !other.check_ptr() {
+                return None;
+            }
+            let len: usize = self.memory_length.min(other.memory_length);
+            let mut sum: T = T::default();
+            for i in 0..len {
                 unsafe {
-                    let layout: std::alloc::Layout = std::alloc::Layout::new::<T>();
-                    std::alloc::dealloc(self.ptr as *mut u8, layout);
+                    sum = sum + *self.ptr.add(i) * *other.ptr.add(i);
                 }
             }
-            self.memory_length = 0;
-            self.offset = 0;
-            self.ptr = std::ptr::null();
+            Some(sum)
         }
-    }
-}
 
-pub mod mut_raw_ptr {
-    pub struct MutRawPtr<T> 
-    where  T: Sized + Copy + Send + Sync
-    {
-        ptr: *mut T,
-        memory_length: usize,
-        offset: usize,
-    }
-
-    impl<T: Sized + Copy + Send + Sync> MutRawPtr<T> {
-        /// Allocates memory for an array of `memory_length` elements of type `T` and returns a mutable raw pointer to the allocated memory.
-        ///
-        /// # Parameters
-        ///
-        /// - `memory_length`: The number of elements of type `T` to allocate memory for. Must be greater than 0.
-        ///
-        /// # Returns
-        ///
-        /// - `Some(*mut T)`: A mutable raw pointer to the allocated memory if successful.
-        /// - `None`: If `memory_length` is 0 or less.
-        ///
-        /// # Panics
-        ///
-        /// This function will panic if the alignment or size parameters are invalid. This includes:
-        /// - Alignment being zero or not a power of two.
-        /// - The size, when rounded up to the nearest multiple of alignment, overflows `isize` (i.e., the rounded value must be less than or equal to `isize::MAX`).
-        ///
-        /// # Safety
-        ///
-        /// This function is unsafe because it performs a raw allocation and returns a raw pointer. The caller is responsible for ensuring that the memory is properly initialized and freed.
-        ///
-        /// # Example
-        ///
+        /// Returns the number of elements before the first `T::default()` element, starting at the current offset.
+        /// 
+        /// This method mirrors C's `strlen` for arbitrary element types. The scan never reads past the memory 
+        /// length, so if no terminator is found the number of remaining elements is returned.
+        /// 
+        /// # Examples
+        /// 
         /// ```rust
-        /// let alloc: *mut i32 = MutRawPtr::c_malloc(1).unwrap();
-        /// let _: MutRawPtr<i32> = MutRawPtr::new(t, 1, 1);
+        /// let len = ptr.nul_terminated_len().unwrap();
         /// ```
-        pub fn c_malloc(memory_length: usize) -> Option<*mut T> {
-            if memory_length <= 0 {
+        pub fn nul_terminated_len(&self) -> Option<usize>
+        where T: PartialEq + Default
+        {
+            if !self.check_ptr() || !self.check_bounds() {
                 return None;
             }
-
-            let size: usize = std::mem::size_of::<T>() * memory_length;
-            let align: usize = std::mem::align_of::<T>();
-            let layout: std::alloc::Layout = std::alloc::Layout::from_size_align(size, align).expect("Invalid alignment or size parameters.");
-
-            unsafe {
-                let alloc: *mut T = std::alloc::alloc(layout) as *mut T;
-                return Some(alloc);
-            } 
+            let start: usize = self.offset;
+            let terminator: T = T::default();
+            let len: usize = (start..self.memory_length)
+                .take_while(|&i| unsafe { *self.ptr.add(i) } != terminator)
+                .count();
+            Some(len)
         }
 
-        /// Creates a new `MutRawPtr` with the given pointer, memory length, and offset.
-        /// 
-        /// This method ensures that the pointer is properly aligned and that the offset is within the bounds 
-        /// of the allocated memory length.
+        /// Returns a reversed, non-owning view over the memory block.
         /// 
-        /// # Panics
-        /// 
-        /// Panics if the pointer is not aligned to `T` or if the offset is not within the bounds of the memory length.
+        /// This method does not copy any data; the returned `RevView` reads through the original pointer.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// let alloc_ptr: *mut i32 = ...; // Assume this is a properly allocated and aligned pointer either from C or using Rust's std::alloc::alloc and std::alloc::Layout otherwise it will panic.
-        /// let ptr = MutRawPtr::new(alloc_ptr, 1, 1);
+        /// let view = ptr.rev_view();
         /// ```
         #[inline]
-        pub fn new(ptr: *mut T, memory_length: usize, offset: usize) -> Self {
-            assert!((ptr as usize) % std::mem::align_of::<T>() == 0, "box_raw_ptr Err: Memory Not Aligned");
-            assert!(offset <= memory_length && offset > 0, "box_raw_ptr Err: Offset Is Not Within Bounds");
-            Self { ptr, memory_length, offset, }
+        pub fn rev_view(&self) -> RevView<'_, T> {
+            RevView { base: self }
         }
 
-        /// Creates a new `MutRawPtr` with a null mutable pointer and zero memory length and offset.
+        /// Copies the elements from the current offset to the end of the memory block into a new `Vec`.
         /// 
-        /// This is useful for creating a placeholder `MutRawPtr` that can later be assigned a valid mutable pointer.
+        /// This is the "rest of the buffer" operation used when consuming the tail of a parsed block.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// let null_ptr = MutRawPtr::<i32>::nullptr();
+        /// let rest: Vec<u8> = ptr.remaining_to_vec().unwrap();
         /// ```
-        #[inline]
-        pub fn nullptr() -> Self {
-            Self { ptr: std::ptr::null_mut(), memory_length: 0, offset: 0 }
+        pub fn remaining_to_vec(&self) -> Option<Vec<T>> {
+            if !self.check_ptr() || !self.check_bounds() {
+                return None;
+            }
+            let start: usize = self.offset;
+            Some((start..self.memory_length).map(|i| unsafe { *self.ptr.add(i) }).collect())
         }
 
-        /// Manually drops the `MutRawPtr` instance.
-        /// 
-        /// # Safety
+        /// Returns the zero-based index of the maximum element in the memory block.
         /// 
-        /// This function is unsafe because it drops the instance manually, which can lead to undefined behavior 
-        /// if the instance is used after being dropped.
+        /// If several elements are equally maximal, the index of the first one is returned.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// unsafe {
-        ///     mut_ptr.manual_drop();
-        /// }
+        /// let index = ptr.argmax().unwrap();
         /// ```
-        #[inline]
-        pub unsafe fn manual_drop(self) -> () {
-            drop(self);
+        pub fn argmax(&self) -> Option<usize>
+        where T: Ord
+        {
+            if !self.check_ptr() || self.memory_length == 0 {
+                return None;
+            }
+            let mut best: usize = 0;
+            for i in 1..self.memory_length {
+                if unsafe { *self.ptr.add(i) > *self.ptr.add(best) } {
+                    best = i;
+                }
+            }
+            Some(best)
         }
 
-        /// Checks if the current offset is within the bounds of the memory length.
+        /// Returns the zero-based index of the minimum element in the memory block.
         /// 
-        /// This method ensures that the mutable pointer is pointing to a valid position within the allocated memory block.
+        /// If several elements are equally minimal, the index of the first one is returned.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// assert!(mut_ptr.check_bounds());
+        /// let index = ptr.argmin().unwrap();
         /// ```
-        #[inline]
-        pub fn check_bounds(&self) -> bool {
-            (1..=self.memory_length).contains(&self.offset)
+        pub fn argmin(&self) -> Option<usize>
+        where T: Ord
+        {
+            if !self.check_ptr() || self.memory_length == 0 {
+                return None;
+            }
+            let mut best: usize = 0;
+            for i in 1..self.memory_length {
+                if unsafe { *self.ptr.add(i) < *self.ptr.add(best) } {
+                    best = i;
+                }
+            }
+            Some(best)
         }
 
-        /// Checks if the mutable pointer is not null and properly aligned.
+        /// Checks if the allocation's byte range overlaps the byte range of a slice.
         /// 
-        /// This method ensures that the mutable pointer is valid and meets the alignment requirements of `T`.
+        /// This is useful for choosing between `std::ptr::copy_nonoverlapping` and `std::ptr::copy` when one 
+        /// side of a copy is an external slice. A null pointer or an empty slice never aliases.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// assert!(mut_ptr.check_ptr());
+        /// assert!(!ptr.aliases_slice(&[1, 2, 3]));
         /// ```
-        pub fn check_ptr(&self) -> bool {
-            if self.ptr.is_null() {
+        pub fn aliases_slice(&self, s: &[T]) -> bool {
+            let size: usize = std::mem::size_of::<T>();
+            if self.ptr.is_null() || self.capacity == 0 || s.is_empty() || size == 0 {
                 return false;
             }
-            let align: usize = std::mem::align_of::<T>();
-            (self.ptr as usize) % align == 0
+            let start: usize = self.ptr as usize;
+            let end: usize = start + size * self.capacity;
+            let s_start: usize = s.as_ptr() as usize;
+            let s_end: usize = s_start + std::mem::size_of_val(s);
+            start < s_end && s_start < end
         }
 
-        /// Returns the current offset.
+        /// Reads the element `back` positions from the end of the memory block, if valid.
         /// 
-        /// This method provides the current offset within the memory block.
+        /// `back == 0` reads the last element, `back == 1` the second to last, and so on. The offset is not 
+        /// changed.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// let offset = mut_ptr.check_offset();
+        /// let last = ptr.read_from_end(0).unwrap();
         /// ```
-        pub fn check_offset(&self) -> usize {
-            self.offset
+        pub fn read_from_end(&self, back: usize) -> Option<T> {
+            if !self.check_ptr() || back >= self.memory_length {
+                return None;
+            }
+            Some( unsafe { *self.ptr.add(self.memory_length - 1 - back) } )
         }
 
-        /// Returns the current memory length.
+        /// Applies `f` to corresponding elements of two memory blocks, collecting the results into a new block.
         /// 
-        /// This method provides the total length of the memory block that the mutable pointer points to.
+        /// This method operates over the shorter of the two memory lengths. The results are written into a 
+        /// freshly allocated `MutRawPtr<V>` whose offset starts at the first element.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// let length = mut_ptr.check_memory_length();
+        /// let sums: MutRawPtr<i64> = ptr.zip_map(&other, |a, b| a as i64 + b as i64).unwrap();
         /// ```
-        pub fn check_memory_length(&self) -> usize {
-            self.memory_length
+        pub fn zip_map<U, V, F>(&self, other: &ConstRawPtr<U>, mut f: F) -> Option<super::mut_raw_ptr::MutRawPtr<V>>
+        where U: Sized + Copy + Send + Sync, V: Sized + Copy + Send + Sync, F: FnMut(T, U) -> V
+        {
+            if !self.check_ptr() || !other.check_ptr() {
+                return None;
+            }
+            let len: usize = self.memory_length.min(other.memory_length);
+            let mapped: Vec<V> = (0..len)
+                .map(|i| unsafe { f(*self.ptr.add(i), *other.ptr.add(i)) })
+                .collect();
+            super::mut_raw_ptr::MutRawPtr::c_malloc_from_slice(&mapped)
         }
 
-        /// Changes the offset by a given index, if the resulting offset is within bounds.
+        /// Recomputes the offset from a pointer into the memory block, such as a cursor advanced by C code.
         /// 
-        /// This method allows you to move the mutable pointer by a specified index within the memory block, 
-        /// ensuring that the new offset is within bounds.
+        /// The offset is set to the element `current` points at, computed as `(current - base) / size_of::<T>()`. 
+        /// This re-establishes the offset after C code has advanced its own copy of the pointer. `current` is 
+        /// only compared against the base, never dereferenced.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the offset was updated.
+        /// - `None`: If the pointer is invalid, or `current` is not a whole number of elements past the base or 
+        ///   lies outside the memory length. The offset is left unchanged in that case.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// assert!(mut_ptr.change_offset(2).is_some());
+        /// ptr.sync_offset_from_base(c_cursor).unwrap();
         /// ```
-        pub fn change_offset(&mut self, index: isize) -> Option<()> {
-            if !self.check_ptr() {
+        pub fn sync_offset_from_base(&mut self, current: *const T) -> Option<()> {
+            let size: usize = std::mem::size_of::<T>();
+            if !self.check_ptr() || size == 0 {
                 return None;
             }
-            let new_offset: isize = self.offset as isize + index;
-            if new_offset > 0 && new_offset <= self.memory_length as isize {
-                self.offset = new_offset as usize;
-                Some(())
-            } else {
-                None
+            let distance: usize = (current as usize).checked_sub(self.ptr as usize)?;
+            if !distance.is_multiple_of(size) || distance / size >= self.memory_length {
+                return None;
             }
+            self.offset = distance / size;
+            Some(())
         }
 
-        /// Changes the memory length, if the new length is valid.
+        /// Reads the field located `field_byte_offset` bytes into every element and collects the values.
+        /// 
+        /// This extracts one column from an array of structs. `field_byte_offset` is typically obtained with 
+        /// `std::mem::offset_of!`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(Vec<F>)`: The field value of every element, in order.
+        /// - `None`: If the pointer is invalid, the field does not fit within `T`, or the field is not aligned 
+        ///   for `F` in every element.
+        /// 
+        /// # Safety
+        /// 
+        /// The `size_of::<F>()` bytes at `field_byte_offset` must be initialized and form a valid value of `F` in 
+        /// every element, which holds when they are a field of type `F` located with `std::mem::offset_of!`.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// assert!(mut_ptr.change_memory_length(10).is_some());
+        /// let column: Vec<i32> = unsafe { ptr.field_column(std::mem::offset_of!(Data, a)) }.unwrap();
         /// ```
-        pub fn change_memory_length(&mut self, memory_length: usize) -> Option<()> {
-            if memory_length <= 0 || self.offset > memory_length {
+        pub unsafe fn field_column<F: Copy>(&self, field_byte_offset: usize) -> Option<Vec<F>> {
+            if !self.check_ptr() {
+                return None;
+            }
+            let size: usize = std::mem::size_of::<T>();
+            let align: usize = std::mem::align_of::<F>();
+            if field_byte_offset.checked_add(std::mem::size_of::<F>())? > size {
+                return None;
+            }
+            let first: usize = self.ptr as usize + field_byte_offset;
+            if !first.is_multiple_of(align) || !size.is_multiple_of(align) {
                 return None;
             }
+            let column: Vec<F> = (0..self.memory_length)
+                .map(|i| unsafe { *((self.ptr.add(i) as *const u8).add(field_byte_offset) as *const F) })
+                .collect();
+            Some(column)
+        }
 
-            self.memory_length = memory_length;
-            Some(())
+        /// Deep-copies the memory block into a new, independently owned `ConstRawPtr`.
+        /// 
+        /// The snapshot has its own allocation with the same memory length and offset, so it stays valid 
+        /// after the original has been dropped.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let snapshot = ptr.snapshot().unwrap();
+        /// drop(ptr);
+        /// let value = snapshot.unwrap().unwrap();
+        /// ```
+        pub fn snapshot(&self) -> Option<ConstRawPtr<T>> {
+            if !self.check_ptr() || !self.check_bounds() {
+                return None;
+            }
+            let snapshot: ConstRawPtr<T> = unsafe { Self::c_malloc(self.memory_length, self.offset) }?;
+            unsafe { std::ptr::copy_nonoverlapping(self.ptr, snapshot.ptr as *mut T, self.memory_length) };
+            Some(snapshot)
         }
 
-        /// Releases the mutable pointer and returns the value it points to, if valid.
+        /// Finds the longest run of consecutive elements equal to `value`.
         /// 
-        /// This method takes ownership of the mutable pointer and returns the value it points to, ensuring that 
-        /// the pointer is valid and properly aligned.
+        /// Returns the zero-based start index and the length of the run. If several runs are equally long, 
+        /// the first one is returned.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// let value = mut_ptr.release_ptr().unwrap();
+        /// let (start, length) = ptr.longest_run(0).unwrap();
         /// ```
-        pub fn release_ptr(self) -> Option<T> {
-            if self.check_ptr() {
-                unsafe {
-                    let ptr: T = *self.ptr;
-                    drop(self);
-                    Some(ptr)
+        pub fn longest_run(&self, value: T) -> Option<(usize, usize)>
+        where T: PartialEq
+        {
+            if !self.check_ptr() {
+                return None;
+            }
+            let mut best: Option<(usize, usize)> = None;
+            let mut start: usize = 0;
+            let mut len: usize = 0;
+            for i in 0..self.memory_length {
+                if unsafe { *self.ptr.add(i) } == value {
+                    if len == 0 {
+                        start = i;
+                    }
+                    len += 1;
+                    if best.is_none_or(|(_, best_len)| len > best_len) {
+                        best = Some((start, len));
+                    }
+                } else {
+                    len = 0;
                 }
-            } else {
-                None
             }
+            best
         }
 
-        /// Sets the mutable pointer to null and resets the memory length and offset.
+        /// Reads the element at column `x` and row `y` of a 2D grid stored with `row_stride` elements per row.
         /// 
-        /// This method is useful for invalidating a mutable pointer and ensuring that it no longer points to any memory.
+        /// The element is read from index `y * row_stride + x`, which handles rows padded beyond their visible 
+        /// width as is common in framebuffers. The offset is not changed.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element at `(x, y)`.
+        /// - `None`: If the pointer is invalid, `x >= row_stride`, or the index lies outside the memory length.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// mut_ptr.set_null();
+        /// let pixel = ptr.pixel_at(3, 2, 5).unwrap();
         /// ```
-        #[inline]
-        pub fn set_null(&mut self) -> () {
-            if self.check_ptr() {
-                self.memory_length = 0;
-                self.offset = 0;
-                self.ptr = std::ptr::null_mut();
+        pub fn pixel_at(&self, x: usize, y: usize, row_stride: usize) -> Option<T> {
+            if !self.check_ptr() || x >= row_stride {
+                return None;
             }
+            let index: usize = y.checked_mul(row_stride)?.checked_add(x)?;
+            if index >= self.memory_length {
+                return None;
+            }
+            Some( unsafe { *self.ptr.add(index) } )
         }
 
-        /// Returns the memory address of the mutable pointer as a hexadecimal string.
+        /// Computes the absolute difference of corresponding elements into a newly allocated memory block.
         /// 
-        /// This method is useful for debugging and logging purposes to inspect the raw memory address.
+        /// This method operates over the shorter of the two memory lengths, setting each element of the result 
+        /// to `a.abs_diff(b)`. See `Integer::abs_diff` for the behavior of signed types.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// let address = mut_ptr.memory_address();
+        /// let diff: MutRawPtr<u8> = ptr.abs_diff(&other).unwrap();
+        /// ```
+        pub fn abs_diff(&self, other: &Self) -> Option<super::mut_raw_ptr::MutRawPtr<T>>
+        where T: super::integer::Integer
+        {
+            self.zip_map(other, |a, b| a.abs_diff(b))
+        }
+
+        /// Checks if the elements of the memory block are in non-decreasing order.
+        /// 
+        /// This is useful for validating the precondition of a binary search.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(ptr.is_sorted().unwrap());
+        /// ```
+        pub fn is_sorted(&self) -> Option<bool>
+        where T: Ord
+        {
+            if !self.check_ptr() {
+                return None;
+            }
+            Some((1..self.memory_length).all(|i| unsafe { *self.ptr.add(i - 1) <= *self.ptr.add(i) }))
+        }
+
+        /// Returns a non-owning view of the pointer with an independent offset.
+        /// 
+        /// The fork shares the memory block, memory length, and offset of `self`, but moving its offset does not 
+        /// affect the original. Unlike `clone`, the fork never deallocates, so dropping it cannot free the 
+        /// memory block out from under the original. The fork borrows `self`, so it can't outlive it.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let mut cursor = ptr.fork();
+        /// cursor.change_offset(1).unwrap();
+        /// ```
+        /// 
+        /// The fork cannot outlive the original:
+        /// 
+        /// ```compile_fail,E0597
+        /// use box_raw_ptr::const_raw_ptr::ConstRawPtr;
+        /// 
+        /// let cursor = {
+        ///     let ptr: ConstRawPtr<i32> = unsafe { ConstRawPtr::c_malloc(4, 0) }.unwrap();
+        ///     ptr.fork()
+        /// };
         /// ```
         #[inline]
-        pub fn memory_address(&self) -> String {
-            format!("{:x}", self.ptr as usize)
+        pub fn fork(&self) -> BorrowedConstRawPtr<'_, T> {
+            BorrowedConstRawPtr::new(ConstRawPtr { ptr: self.ptr, memory_length: self.memory_length, capacity: self.capacity, offset: self.offset, owned: false, align: self.align })
         }
 
-        /// Converts the `MutRawPtr` to a constant pointer (`ConstRawPtr`).
+        /// Returns the `Layout` describing the full allocation behind the pointer.
         /// 
-        /// This method creates a constant version of the `MutRawPtr`, which allows for read-only access to the 
-        /// underlying data.
+        /// The layout covers `capacity` elements of `T` (which equals the memory length unless the block was 
+        /// shrunk in place) with the alignment it was allocated with, matching what `c_malloc` or 
+        /// `c_malloc_aligned` allocated.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// let const_ptr = mut_ptr.as_const();
+        /// let layout = ptr.layout().unwrap();
         /// ```
-        pub fn as_const(&self) -> super::const_raw_ptr::ConstRawPtr<T> {
-            super::const_raw_ptr::ConstRawPtr::new(self.ptr as *const T, self.memory_length, self.offset)
+        pub fn layout(&self) -> Option<std::alloc::Layout> {
+            if self.ptr.is_null() {
+                return None;
+            }
+            std::alloc::Layout::from_size_align(std::mem::size_of::<T>().checked_mul(self.capacity)?, self.align).ok()
         }
 
-        /// Unwraps the mutable pointer and returns the value it points to, if valid.
+        /// Computes the moving average over every `window` consecutive elements into a newly allocated block.
         /// 
-        /// This method returns the value that the mutable pointer points to, ensuring that the pointer is valid and 
-        /// properly aligned.
+        /// The result has `memory_length - window + 1` elements, where element `i` is the mean of elements 
+        /// `i..i + window` of the source.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(MutRawPtr<f64>)`: The moving averages.
+        /// - `None`: If the pointer is invalid, `window` is 0, or `window > memory_length`.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// let value = mut_ptr.unwrap().unwrap();
+        /// let smoothed: MutRawPtr<f64> = ptr.moving_average(3).unwrap();
         /// ```
-        pub fn unwrap(self) -> Option<T> {
-            if self.check_ptr() {
-                Some( unsafe { *self.ptr } )
-            } else {
-                None
+        pub fn moving_average(&self, window: usize) -> Option<super::mut_raw_ptr::MutRawPtr<f64>>
+        where T: Into<f64>
+        {
+            if !self.check_ptr() || window == 0 || window > self.memory_length {
+                return None;
             }
+            let values: Vec<f64> = (0..self.memory_length).map(|i| unsafe { *self.ptr.add(i) }.into()).collect();
+            let mut sum: f64 = values[..window].iter().sum();
+            let mut averages: Vec<f64> = Vec::with_capacity(values.len() - window + 1);
+            averages.push(sum / window as f64);
+            for i in window..values.len() {
+                sum += values[i] - values[i - window];
+                averages.push(sum / window as f64);
+            }
+            super::mut_raw_ptr::MutRawPtr::c_malloc_from_slice(&averages)
         }
 
-        /// Returns a reference to the value the mutable pointer points to, if valid.
+        /// Views the whole memory block as a slice.
         /// 
-        /// This method provides a reference to the value that the mutable pointer points to, ensuring that the pointer 
-        /// is valid and properly aligned.
+        /// The slice starts at the base of the allocation, not at the current offset, and spans `memory_length` elements.
+        /// The caller must guarantee every element in `0..memory_length` was initialized, as required by 
+        /// `std::slice::from_raw_parts`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(&[T])`: The memory block as a slice.
+        /// - `None`: If the pointer is invalid.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// let reference = mut_ptr.ref_const().unwrap();
+        /// let total: i32 = ptr.as_slice().unwrap().iter().sum();
         /// ```
-        pub fn ref_const(&self) -> Option<&T> {
-            if self.check_ptr() {
-                Some( unsafe { & *self.ptr } )
-            } else {
-                None
+        pub fn as_slice(&self) -> Option<&[T]> {
+            if !self.check_ptr() {
+                return None;
             }
+            Some(unsafe { std::slice::from_raw_parts(self.ptr, self.memory_length) })
         }
 
-        /// Returns a mutable reference to the value the mutable pointer points to, if valid.
+        /// Corrects the memory length of a pointer adopted with the wrong length.
         /// 
-        /// This method provides a mutable reference to the value that the mutable pointer points to, ensuring that 
-        /// the pointer is valid and properly aligned.
+        /// Unlike `change_memory_length`, the new length is not limited by the capacity, and nothing is clamped. 
+        /// The capacity is corrected along with the memory length, since both were taken from the wrong length 
+        /// when the pointer was adopted.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the memory length was corrected.
+        /// - `None`: If the pointer is invalid, the current offset would fall outside `actual_length`, or 
+        ///   `actual_length` elements of `T` overflow `isize`.
+        /// 
+        /// # Safety
+        /// 
+        /// `actual_length` must be the true number of elements of the allocation. Every method bounded by the 
+        /// memory length reads or writes up to it, and an owned pointer deallocates a layout computed from it.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// let mut reference = mut_ptr.ref_mut().unwrap();
-        /// *reference = 42;
+        /// assert!(unsafe { ptr.with_corrected_length(8) }.is_some());
         /// ```
-        pub fn ref_mut(&self) -> Option<&mut T> {
-            if self.check_ptr() {
-                unsafe { Some(&mut *self.ptr) }
-            } else {
-                None
+        pub unsafe fn with_corrected_length(&mut self, actual_length: usize) -> Option<()> {
+            if !self.check_ptr() || self.offset >= actual_length || std::alloc::Layout::array::<T>(actual_length).is_err() {
+                return None;
             }
+            self.memory_length = actual_length;
+            self.capacity = actual_length;
+            Some(())
         }
 
-        /// Checks if the mutable pointer is null.
+        /// Copies the whole memory block into a new `Vec<T>`.
         /// 
-        /// This method determines if the mutable pointer is null, which is useful for validation and error checking.
+        /// The copy starts at the base of the allocation, not at the current offset, and spans `memory_length` 
+        /// elements. The pointer keeps ownership of the memory block and can still be used afterwards.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(Vec<T>)`: A copy of the memory block.
+        /// - `None`: If the pointer is invalid.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// assert!(mut_ptr.is_null());
+        /// let copy: Vec<i32> = ptr.to_vec().unwrap();
         /// ```
-        #[inline]
-        pub fn is_null(&self) -> bool {
-            self.ptr.is_null()
+        pub fn to_vec(&self) -> Option<Vec<T>> {
+            if !self.check_ptr() {
+                return None;
+            }
+            let mut vec: Vec<T> = Vec::with_capacity(self.memory_length);
+            unsafe {
+                std::ptr::copy_nonoverlapping(self.ptr, vec.as_mut_ptr(), self.memory_length);
+                vec.set_len(self.memory_length);
+            }
+            Some(vec)
         }
 
-        /// Returns the size of the type `T`.
+        /// Gathers the elements at the given indices into a `Vec<T>`, in the order of `indices`.
         /// 
-        /// This method provides the size of the type `T` in bytes, which is useful for memory allocation and 
-        /// pointer arithmetic.
+        /// Indices are relative to the base of the allocation and may repeat, which makes this suitable for 
+        /// permutations as well as selections.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(Vec<T>)`: The gathered elements.
+        /// - `None`: If the pointer is invalid or any index is `>= memory_length`.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// let size = mut_ptr.size_of();
+        /// let picked: Vec<i32> = ptr.gather(&[2, 0, 3]).unwrap();
+        /// ```
+        pub fn gather(&self, indices: &[usize]) -> Option<Vec<T>> {
+            if !self.check_ptr() || indices.iter().any(|&i| i >= self.memory_length) {
+                return None;
+            }
+            Some(indices.iter().map(|&i| unsafe { *self.ptr.add(i) }).collect())
+        }
+
+        /// Returns an iterator over the elements of the memory block.
+        /// 
+        /// The iterator yields each element in `0..memory_length` by value, starting at the base of the 
+        /// allocation, and yields nothing if the pointer is invalid.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let reversed: Vec<i32> = ptr.iter().rev().collect();
+        /// ```
+        pub fn iter(&self) -> ConstRawPtrIter<'_, T> {
+            let back: usize = if self.check_ptr() { self.memory_length } else { 0 };
+            ConstRawPtrIter { base: self, front: 0, back }
+        }
+
+        /// Sets whether the pointer owns its memory block.
+        /// 
+        /// An owning pointer deallocates the memory block with this library's allocator when it is dropped. Only 
+        /// opt in for memory allocated by that allocator (e.g. with `std::alloc::alloc`); memory owned by C, 
+        /// the stack, or another Rust value must stay non-owned to avoid a double free.
+        /// 
+        /// # Safety
+        /// 
+        /// If `owned` is `true`, the memory block must have been allocated by this library's allocator with the 
+        /// layout of `capacity` elements of `T`, and nothing else may free it, including another owning pointer.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let mut ptr = ConstRawPtr::new(alloc_ptr, 1, 0);
+        /// unsafe { ptr.set_owned(true) };
         /// ```
         #[inline]
-        pub fn size_of(&self) -> usize {
-            std::mem::size_of::<T>()
+        pub unsafe fn set_owned(&mut self, owned: bool) {
+            self.owned = owned;
         }
 
-        /// Casts the mutable pointer to a `MutRawPtr` of another type `U`.
+        /// Reads the element at `index`, relative to the base of the allocation, without moving the offset.
         /// 
-        /// This method allows you to reinterpret the mutable pointer as a different type, ensuring that the new type 
-        /// is compatible and properly aligned.
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element at `index`.
+        /// - `None`: If the pointer is invalid or `index >= memory_length`.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// let new_ptr = mut_ptr.cast_ptr::<f64>().unwrap();
+        /// let third: i32 = ptr.read_at(2).unwrap();
         /// ```
-        pub fn cast_ptr<U: Sized + Copy + Send + Sync>(&self) -> Option<MutRawPtr<U>> {
-            if !self.ptr.is_null() {
-                Some(MutRawPtr {
-                    ptr: self.ptr as *mut U,
-                    memory_length: self.memory_length,
-                    offset: self.offset,
-                })
-            } else {
-                None
-            }
+        pub fn read_at(&self, index: usize) -> Option<T> {
+            self.try_read_at(index).ok()
         }
 
-        /// Writes a value into the memory location pointed to by the mutable pointer.
+        /// Performs a volatile read of the element at the current offset.
         /// 
-        /// This method writes a value into the memory location pointed to by the mutable pointer, ensuring that 
-        /// the pointer is valid and properly aligned.
+        /// Volatile reads are never elided or reordered with other volatile accesses by the compiler, which makes 
+        /// this suitable for memory-mapped I/O such as hardware registers.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element at the current offset.
+        /// - `None`: If the pointer is invalid or the offset is out of bounds.
         /// 
         /// # Examples
         /// 
         /// ```rust
-        /// mut_ptr.write_ptr(42);
+        /// let status: u32 = register.read_volatile().unwrap();
         /// ```
-        pub fn write_ptr(&mut self, src: T) -> Option<()> {
-            if self.check_ptr() {
+        pub fn read_volatile(&self) -> Option<T> {
+            if !self.check_ptr() || !self.check_bounds() {
                 return None;
             }
-            unsafe {
-                std::ptr::write(self.ptr, src);
-            }
-            Some(())
+            Some( unsafe { std::ptr::read_volatile(self.ptr.add(self.offset)) } )
         }
-    }
 
-    impl<T: Sized + Copy + Send + Sync> Clone for MutRawPtr<T> {
-        fn clone(&self) -> Self {
-            Self { ptr: self.ptr.clone(), memory_length: self.memory_length, offset: self.offset }
+        /// Reads the element at the current offset without requiring the pointer to be aligned.
+        /// 
+        /// Uses `std::ptr::read_unaligned`, so it works for pointers created with `new_unaligned`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element at the current offset.
+        /// - `None`: If the pointer is null or the offset is out of bounds.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let value: u32 = ptr.access_unaligned().unwrap();
+        /// ```
+        pub fn access_unaligned(&self) -> Option<T> {
+            if self.ptr.is_null() || !self.check_bounds() {
+                return None;
+            }
+            Some( unsafe { std::ptr::read_unaligned(self.ptr.add(self.offset)) } )
         }
-    }
 
-    impl<T: Sized + Copy + Send + Sync> std::fmt::Debug for MutRawPtr<T> {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            f.debug_struct("MutRawPtr")
-                .field("ptr", &self.ptr)
-                .field("memory_length", &self.memory_length)
-                .field("offset", &self.offset)
-                .finish()
+        /// Checks the pointer like `check_ptr`, reporting why it is invalid.
+        fn validate_ptr(&self) -> Result<(), super::error::BoxRawPtrError> {
+            if self.ptr.is_null() {
+                return Err(super::error::BoxRawPtrError::Null);
+            }
+            if !(self.ptr as usize).is_multiple_of(std::mem::align_of::<T>()) {
+                return Err(super::error::BoxRawPtrError::Misaligned);
+            }
+            Ok(())
         }
-    }
 
-    impl<T: Sized + Copy + Send + Sync> PartialEq for MutRawPtr<T> {
-        fn eq(&self, other: &Self) -> bool {
-            self.ptr == other.ptr
+        /// Reads the element at the current offset, if valid.
+        /// 
+        /// See `try_access` for the reason of a failure.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let value: i32 = ptr.access().unwrap();
+        /// ```
+        pub fn access(&self) -> Option<T> {
+            self.try_access().ok()
         }
 
-        fn ne(&self, other: &Self) -> bool {
-            self.ptr != other.ptr
+        /// Reads the element at the current offset.
+        /// 
+        /// # Errors
+        /// 
+        /// - `BoxRawPtrError::Null` or `BoxRawPtrError::Misaligned`: If the pointer is invalid.
+        /// - `BoxRawPtrError::OutOfBounds`: If the offset is outside the memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let value: i32 = ptr.try_access()?;
+        /// ```
+        pub fn try_access(&self) -> Result<T, super::error::BoxRawPtrError> {
+            self.validate_ptr()?;
+            if !self.check_bounds() {
+                return Err(super::error::BoxRawPtrError::OutOfBounds);
+            }
+            Ok( unsafe { *self.ptr.add(self.offset) } )
         }
-    }
 
-    impl<T: Sized + Copy + Send + Sync> Drop for MutRawPtr<T> {
-        fn drop(&mut self) {
-            if self.check_ptr() {
-                unsafe {
-                    let layout: std::alloc::Layout = std::alloc::Layout::new::<T>();
-                    std::alloc::dealloc(self.ptr as *mut u8, layout);
+        /// Changes the offset by a given index, if the resulting offset is within bounds.
+        /// 
+        /// # Errors
+        /// 
+        /// - `BoxRawPtrError::Null` or `BoxRawPtrError::Misaligned`: If the pointer is invalid.
+        /// - `BoxRawPtrError::OutOfBounds`: If the resulting offset is outside the memory block or the addition 
+        ///   overflows. The offset is left unchanged.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// ptr.try_change_offset(2)?;
+        /// ```
+        pub fn try_change_offset(&mut self, index: isize) -> Result<(), super::error::BoxRawPtrError> {
+            self.validate_ptr()?;
+            match self.offset.checked_add_signed(index) {
+                Some(new_offset) if new_offset < self.memory_length => {
+                    self.offset = new_offset;
+                    Ok(())
                 }
+                _ => Err(super::error::BoxRawPtrError::OutOfBounds),
             }
-            self.memory_length = 0;
-            self.offset = 0;
-            self.ptr = std::ptr::null_mut();
         }
-    }
-}
 
-#[cfg(test)]
+        /// Reads the element at `index`, relative to the base of the allocation, without moving the offset.
+        /// 
+        /// # Errors
+        /// 
+        /// - `BoxRawPtrError::Null` or `BoxRawPtrError::Misaligned`: If the pointer is invalid.
+        /// - `BoxRawPtrError::OutOfBounds`: If `index >= memory_length`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let third: i32 = ptr.try_read_at(2)?;
+        /// ```
+        pub fn try_read_at(&self, index: usize) -> Result<T, super::error::BoxRawPtrError> {
+            self.validate_ptr()?;
+            if index >= self.memory_length {
+                return Err(super::error::BoxRawPtrError::OutOfBounds);
+            }
+            Ok( unsafe { *self.ptr.add(index) } )
+        }
+
+        /// Computes the distance in elements from the current element of `other` to the current element of `self`.
+        /// 
+        /// The result is positive if `self` points past `other`, and accounts for both the base pointers and the 
+        /// offsets, so it also works between sub-views of one allocation.
+        /// 
+        /// # Safety
+        /// 
+        /// Both pointers must be derived from the same allocation, as required by `<*const T>::offset_from`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(isize)`: The distance in elements.
+        /// - `None`: If either pointer is null or `T` is zero-sized.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let distance: isize = unsafe { ptr.offset_from(&other).unwrap() };
+        /// ```
+        pub unsafe fn offset_from(&self, other: &ConstRawPtr<T>) -> Option<isize> {
+            if self.ptr.is_null() || other.ptr.is_null() || std::mem::size_of::<T>() == 0 {
+                return None;
+            }
+            Some(self.ptr.wrapping_add(self.offset).offset_from(other.ptr.wrapping_add(other.offset)))
+        }
+
+        /// Moves the offset to the absolute element `index`, counted from the base of the allocation.
+        /// 
+        /// Unlike `change_offset`, the new position does not depend on the current offset. The base pointer 
+        /// itself never moves, so repeated jumps can't drift away from the allocation.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the offset was moved.
+        /// - `None`: If the pointer is invalid or `index >= memory_length`. The offset is left unchanged.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// ptr.set_offset(3).unwrap();
+        /// ```
+        pub fn set_offset(&mut self, index: usize) -> Option<()> {
+            if !self.check_ptr() || index >= self.memory_length {
+                return None;
+            }
+            self.offset = index;
+            Some(())
+        }
+
+        /// Moves the offset back to the first element of the memory block.
+        /// 
+        /// The offset is stored separately from the base pointer, which never moves, so rewinding only resets 
+        /// the offset to 0 and the pointer keeps its original provenance.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// ptr.reset_offset();
+        /// ```
+        #[inline]
+        pub fn reset_offset(&mut self) {
+            self.offset = 0;
+        }
+
+        /// Resizes the owned memory block to `new_length` elements with `std::alloc::realloc`.
+        /// 
+        /// The elements in the overlapping prefix are preserved and the offset is clamped to the new memory length. 
+        /// Only memory blocks owned by the pointer (e.g. from `c_malloc`) can be resized. On failure the original 
+        /// memory block, memory length, and offset are left intact.
+        /// 
+        /// # Safety
+        /// 
+        /// When growing, the new elements are not initialized. The caller must write them before reading them.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the memory block was resized.
+        /// - `None`: If the pointer is invalid or not owned, `new_length` is 0, or the reallocation failed.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// unsafe { ptr.c_realloc(16).unwrap() };
+        /// ```
+        pub unsafe fn c_realloc(&mut self, new_length: usize) -> Option<()> {
+            if !self.owned || !self.check_ptr() || new_length == 0 {
+                return None;
+            }
+            let layout: std::alloc::Layout = self.layout()?;
+            let new_size: usize = std::alloc::Layout::array::<T>(new_length).ok()?.size();
+
+            let ptr: *const T = std::alloc::realloc(self.ptr as *mut u8, layout, new_size) as *const T;
+            if ptr.is_null() {
+                return None;
+            }
+            #[cfg(feature = "debug-trace")]
+            {
+                super::debug_trace::forget(self.ptr as usize);
+                super::debug_trace::record(ptr as usize, new_length);
+            }
+
+            self.ptr = ptr;
+            self.memory_length = new_length;
+            self.capacity = new_length;
+            self.offset = self.offset.min(new_length - 1);
+            Some(())
+        }
+
+        /// Checks if both pointers point to the same element, by address.
+        /// 
+        /// Unlike `==`, this never dereferences either pointer.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(ptr.fork().ptr_eq(&ptr));
+        /// ```
+        #[inline]
+        pub fn ptr_eq(&self, other: &Self) -> bool {
+            self.ptr.wrapping_add(self.offset) == other.ptr.wrapping_add(other.offset)
+        }
+
+        /// Creates a non-owning view over a single borrowed value.
+        /// 
+        /// The view has a memory length of 1 and an offset of 0, and never deallocates on drop, so it is safe to 
+        /// use with stack or otherwise borrowed memory. It borrows `value`, so it can't outlive it.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let value: i32 = 5;
+        /// let ptr = ConstRawPtr::from_ref(&value);
+        /// ```
+        /// 
+        /// The view cannot outlive the value:
+        /// 
+        /// ```compile_fail,E0597
+        /// use box_raw_ptr::const_raw_ptr::ConstRawPtr;
+        /// 
+        /// let view = {
+        ///     let value: i32 = 5;
+        ///     ConstRawPtr::from_ref(&value)
+        /// };
+        /// ```
+        #[inline]
+        pub fn from_ref(value: &T) -> BorrowedConstRawPtr<'_, T> {
+            let ptr: *const T = value;
+            BorrowedConstRawPtr::new(Self { ptr, memory_length: 1, capacity: 1, offset: 0, owned: false, align: std::mem::align_of::<T>() })
+        }
+
+        /// Returns a `NonNull<T>` pointing at the element at the current offset.
+        /// 
+        /// This is useful for composing with `NonNull`-oriented APIs. The `NonNull` does not carry the memory 
+        /// length or ownership of the pointer.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(NonNull<T>)`: A pointer to the current element.
+        /// - `None`: If the pointer is null.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let non_null: std::ptr::NonNull<i32> = ptr.as_non_null().unwrap();
+        /// ```
+        #[inline]
+        pub fn as_non_null(&self) -> Option<std::ptr::NonNull<T>> {
+            std::ptr::NonNull::new(self.ptr.wrapping_add(self.offset) as *mut T)
+        }
+
+        /// Decomposes the pointer into its base pointer, memory length, and offset without deallocating.
+        /// 
+        /// The memory block is not freed, so ownership passes to the caller, who can rebuild the pointer with 
+        /// `from_raw_parts`. The pair round-trips losslessly for pointers whose capacity equals their memory 
+        /// length and whose alignment is that of `T`, such as pointers from `c_malloc`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let (raw, memory_length, offset) = ptr.into_raw_parts();
+        /// ```
+        pub fn into_raw_parts(self) -> (*const T, usize, usize) {
+            let parts: (*const T, usize, usize) = (self.ptr, self.memory_length, self.offset);
+            std::mem::forget(self);
+            parts
+        }
+
+        /// Rebuilds an owning pointer from the parts returned by `into_raw_parts`.
+        /// 
+        /// # Safety
+        /// 
+        /// `ptr` must point to an allocation of at least `memory_length` elements made by this library's 
+        /// allocator with the alignment of `T`, and nothing else may free it, since the returned pointer 
+        /// deallocates it on drop.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let ptr = unsafe { ConstRawPtr::from_raw_parts(raw, memory_length, offset) };
+        /// ```
+        pub unsafe fn from_raw_parts(ptr: *const T, memory_length: usize, offset: usize) -> Self {
+            Self { ptr, memory_length, capacity: memory_length, offset, owned: true, align: std::mem::align_of::<T>() }
+        }
+
+        /// Returns a reference to the element at `index`, relative to the base of the allocation.
+        /// 
+        /// The reference borrows the pointer, so it can't outlive it.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(&T)`: A reference to the element at `index`.
+        /// - `None`: If the pointer is invalid or `index >= memory_length`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let third: &i32 = ptr.get(2).unwrap();
+        /// ```
+        pub fn get(&self, index: usize) -> Option<&T> {
+            if !self.check_ptr() || index >= self.memory_length {
+                return None;
+            }
+            Some( unsafe { &*self.ptr.add(index) } )
+        }
+
+        /// Returns the number of elements from the current offset to the end of the memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// while ptr.remaining() > 0 { /* ... */ }
+        /// ```
+        pub fn remaining(&self) -> usize {
+            self.memory_length.saturating_sub(self.offset)
+        }
+
+        /// Reads the first element of the memory block without moving the offset.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element at the base of the allocation.
+        /// - `None`: If the pointer is invalid or the memory block is empty.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let head: i32 = ptr.first().unwrap();
+        /// ```
+        pub fn first(&self) -> Option<T> {
+            self.read_at(0)
+        }
+
+        /// Reads the last element of the memory block without moving the offset.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element at `memory_length - 1`.
+        /// - `None`: If the pointer is invalid or the memory block is empty.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let tail: i32 = ptr.last().unwrap();
+        /// ```
+        pub fn last(&self) -> Option<T> {
+            self.read_at(self.memory_length.checked_sub(1)?)
+        }
+
+        /// Returns an iterator over non-overlapping groups of up to `size` elements of the memory block.
+        /// 
+        /// The last group is shorter when `memory_length` is not a multiple of `size`. An invalid pointer yields 
+        /// no groups.
+        /// 
+        /// # Panics
+        /// 
+        /// Panics if `size` is 0.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// for chunk in ptr.chunks(4) { /* ... */ }
+        /// ```
+        pub fn chunks(&self, size: usize) -> std::slice::Chunks<'_, T> {
+            self.as_slice().unwrap_or(&[]).chunks(size)
+        }
+
+        /// Returns an iterator over overlapping groups of exactly `size` elements of the memory block.
+        /// 
+        /// No groups are yielded when `size > memory_length` or the pointer is invalid.
+        /// 
+        /// # Panics
+        /// 
+        /// Panics if `size` is 0.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let increasing: bool = ptr.windows(2).all(|w| w[0] <= w[1]);
+        /// ```
+        pub fn windows(&self, size: usize) -> std::slice::Windows<'_, T> {
+            self.as_slice().unwrap_or(&[]).windows(size)
+        }
+
+        /// Splits the memory block into two non-owned views at `index`.
+        /// 
+        /// The left view covers `[0, index)` and the right view covers `[index, memory_length)` of the same 
+        /// allocation, each with its offset reset to 0. Neither view frees the allocation on drop, and both 
+        /// borrow `self`, so they can't outlive it.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some((BorrowedConstRawPtr<T>, BorrowedConstRawPtr<T>))`: The left and right views.
+        /// - `None`: If the pointer is invalid or `index > memory_length`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let (left, right) = ptr.split_at(2).unwrap();
+        /// ```
+        pub fn split_at(&self, index: usize) -> Option<(BorrowedConstRawPtr<'_, T>, BorrowedConstRawPtr<'_, T>)> {
+            if !self.check_ptr() || index > self.memory_length {
+                return None;
+            }
+            let right_length: usize = self.memory_length - index;
+            let right_ptr: *const T = unsafe { self.ptr.add(index) };
+            let left: ConstRawPtr<T> = ConstRawPtr { ptr: self.ptr, memory_length: index, capacity: index, offset: 0, owned: false, align: self.align };
+            let right: ConstRawPtr<T> = ConstRawPtr { ptr: right_ptr, memory_length: right_length, capacity: right_length, offset: 0, owned: false, align: self.align };
+            Some((BorrowedConstRawPtr::new(left), BorrowedConstRawPtr::new(right)))
+        }
+
+        /// Reads the element at the current offset without moving the cursor.
+        /// 
+        /// This is the same as `access`, named to pair with `advance`, `retreat` and `peek`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let byte: u8 = cursor.current().unwrap();
+        /// ```
+        pub fn current(&self) -> Option<T> {
+            self.access()
+        }
+
+        /// Reads the element at the current offset, then moves the cursor forward by one.
+        /// 
+        /// After the last element is read the offset sits one past the end, where `check_bounds` is false and 
+        /// further calls return `None` until the cursor is moved back with `retreat` or `set_offset`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element the cursor was on.
+        /// - `None`: If the pointer is invalid or the cursor is at the end.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// while let Some(byte) = cursor.advance() { /* ... */ }
+        /// ```
+        pub fn advance(&mut self) -> Option<T> {
+            let value: T = self.access()?;
+            self.offset += 1;
+            Some(value)
+        }
+
+        /// Moves the cursor back by one, then reads the element at the new offset.
+        /// 
+        /// This undoes `advance`, returning the element it returned.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element the cursor moved onto.
+        /// - `None`: If the pointer is invalid or the cursor is at the base of the allocation.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let previous: u8 = cursor.retreat().unwrap();
+        /// ```
+        pub fn retreat(&mut self) -> Option<T> {
+            let value: T = self.read_at(self.offset.checked_sub(1)?)?;
+            self.offset -= 1;
+            Some(value)
+        }
+
+        /// Reads the element `ahead` elements past the current offset without moving the cursor.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element at `offset + ahead`.
+        /// - `None`: If the pointer is invalid or `offset + ahead >= memory_length`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let next: u8 = cursor.peek(1).unwrap();
+        /// ```
+        pub fn peek(&self, ahead: usize) -> Option<T> {
+            self.read_at(self.offset.checked_add(ahead)?)
+        }
+
+        /// Views the whole memory block as raw bytes.
+        /// 
+        /// The slice starts at the base of the allocation and spans `memory_length * size_of::<T>()` bytes, 
+        /// independent of the current offset.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(&[u8])`: The memory block as bytes.
+        /// - `None`: If the pointer is invalid or the byte length overflows `usize`.
+        /// 
+        /// # Safety
+        /// 
+        /// Every element in `0..memory_length` must be initialized and `T` must have no padding bytes, since 
+        /// padding is uninitialized memory and may not be read as `u8`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let checksum: u32 = unsafe { ptr.as_bytes() }.unwrap().iter().map(|&b| b as u32).sum();
+        /// ```
+        pub unsafe fn as_bytes(&self) -> Option<&[u8]> {
+            if !self.check_ptr() {
+                return None;
+            }
+            let bytes: usize = self.memory_length.checked_mul(std::mem::size_of::<T>())?;
+            Some(std::slice::from_raw_parts(self.ptr as *const u8, bytes))
+        }
+
+        /// Reinterprets the memory block as elements of another type `U`.
+        /// 
+        /// This is `cast_ptr` under a name that flags the reinterpretation. The view borrows `self` and never 
+        /// deallocates. The memory length and offset are rescaled to elements of `U`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(BorrowedConstRawPtr<U>)`: The reinterpreted view.
+        /// - `None`: If the pointer is null, is not aligned for `U`, `U` is zero-sized, or the memory block or 
+        ///   capacity is not a whole number of elements of `U`.
+        /// 
+        /// # Safety
+        /// 
+        /// The bytes of the memory block must be initialized and form valid values of `U`, e.g. only `0` or `1` 
+        /// for `bool`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let words = unsafe { bytes.reinterpret::<u32>() }.unwrap();
+        /// ```
+        pub unsafe fn reinterpret<U: Sized + Copy + Send + Sync>(&self) -> Option<BorrowedConstRawPtr<'_, U>> {
+            self.cast_ptr::<U>()
+        }
+
+        /// Returns the index of the first element equal to `needle`.
+        /// 
+        /// The scan covers `0..memory_length` from the base of the allocation, independent of the current offset.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(usize)`: The absolute index of the first match.
+        /// - `None`: If the pointer is invalid or no element matches.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let newline: usize = bytes.position(b'\n').unwrap();
+        /// ```
+        pub fn position(&self, needle: T) -> Option<usize>
+        where T: PartialEq
+        {
+            self.as_slice()?.iter().position(|&value| value == needle)
+        }
+
+        /// Checks if any element of the memory block is equal to `needle`.
+        /// 
+        /// An invalid pointer contains nothing.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(bytes.contains(0));
+        /// ```
+        pub fn contains(&self, needle: T) -> bool
+        where T: PartialEq
+        {
+            self.position(needle).is_some()
+        }
+
+        /// Moves the offset by `count` elements, clamping it to the first or last element.
+        /// 
+        /// Nothing happens if the pointer is invalid or the memory block is empty.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// ptr.saturating_offset(isize::MAX);
+        /// assert_eq!(ptr.check_offset(), ptr.check_memory_length() - 1);
+        /// ```
+        pub fn saturating_offset(&mut self, count: isize) {
+            if !self.check_ptr() || self.memory_length == 0 {
+                return;
+            }
+            self.offset = self.offset.saturating_add_signed(count).min(self.memory_length - 1);
+        }
+
+        /// Moves the offset by `count` elements, wrapping around modulo the memory length.
+        /// 
+        /// Nothing happens if the pointer is invalid or the memory block is empty.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// ptr.wrapping_offset(-1);
+        /// assert_eq!(ptr.check_offset(), ptr.check_memory_length() - 1);
+        /// ```
+        pub fn wrapping_offset(&mut self, count: isize) {
+            if !self.check_ptr() || self.memory_length == 0 {
+                return;
+            }
+            let offset: i128 = (self.offset as i128 + count as i128).rem_euclid(self.memory_length as i128);
+            self.offset = offset as usize;
+        }
+    }
+
+    /// Clones the pointer as a non-owned view of the same memory block.
+    /// 
+    /// Only the original frees an owned allocation, so the clone must not be used after it is dropped.
+    impl<T: Sized + Copy + Send + Sync> Clone for ConstRawPtr<T> {
+        fn clone(&self) -> Self {
+            Self { ptr: self.ptr, memory_length: self.memory_length, capacity: self.capacity, offset: self.offset, owned: false, align: self.align }
+        }
+    }
+
+    impl<T: Sized + Copy + Send + Sync + std::fmt::Debug> std::fmt::Debug for ConstRawPtr<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut debug = f.debug_struct("ConstRawPtr");
+            if self.ptr.is_null() {
+                debug.field("ptr", &format_args!("null"));
+            } else {
+                debug.field("ptr", &format_args!("{:#x}", self.ptr as usize));
+            }
+            debug.field("len", &self.memory_length).field("offset", &self.offset);
+            if let Some(value) = self.access() {
+                debug.field("value", &value);
+            }
+            debug.finish()
+        }
+    }
+
+    impl<T: Sized + Copy + Send + Sync> std::fmt::Pointer for ConstRawPtr<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            std::fmt::Pointer::fmt(&self.ptr, f)
+        }
+    }
+
+    impl<T: Sized + Copy + Send + Sync> std::fmt::LowerHex for ConstRawPtr<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            std::fmt::LowerHex::fmt(&(self.ptr as usize), f)
+        }
+    }
+
+    impl<T: Sized + Copy + Send + Sync> std::fmt::UpperHex for ConstRawPtr<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            std::fmt::UpperHex::fmt(&(self.ptr as usize), f)
+        }
+    }
+
+    /// Compares the values at the current offsets of both pointers.
+    /// 
+    /// This dereferences both pointers, so both must be valid and point to initialized memory. Two null 
+    /// pointers are equal, and two otherwise invalid pointers are equal if `ptr_eq` holds, which keeps the 
+    /// comparison reflexive. An invalid pointer is never equal to a valid one. Use `ptr_eq` to compare 
+    /// addresses instead.
+    impl<T: Sized + Copy + Send + Sync + PartialEq> PartialEq for ConstRawPtr<T> {
+        fn eq(&self, other: &Self) -> bool {
+            if self.ptr.is_null() && other.ptr.is_null() {
+                return true;
+            }
+            match (self.access(), other.access()) {
+                (Some(a), Some(b)) => a == b,
+                (None, None) => self.ptr_eq(other),
+                _ => false,
+            }
+        }
+    }
+
+    impl<T: Sized + Copy + Send + Sync + Eq> Eq for ConstRawPtr<T> {}
+
+    /// Indexes the memory block relative to the base of the allocation, like a slice.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics with `"box_raw_ptr Err: Index Out Of Bounds"` if the pointer is invalid or `index >= memory_length`.
+    /// Use `get` for a non-panicking alternative.
+    impl<T: Sized + Copy + Send + Sync> std::ops::Index<usize> for ConstRawPtr<T> {
+        type Output = T;
+
+        fn index(&self, index: usize) -> &T {
+            self.get(index).expect("box_raw_ptr Err: Index Out Of Bounds")
+        }
+    }
+
+    impl<T: Sized + Copy + Send + Sync> Drop for ConstRawPtr<T> {
+        fn drop(&mut self) {
+            if self.owned && self.check_ptr() {
+                let size: usize = std::mem::size_of::<T>() * self.capacity;
+                let layout: std::alloc::Layout = std::alloc::Layout::from_size_align(size, self.align).expect("Invalid alignment or size parameters.");
+                unsafe {
+                    std::alloc::dealloc(self.ptr as *mut u8, layout);
+                }
+                #[cfg(feature = "debug-trace")]
+                super::debug_trace::forget(self.ptr as usize);
+            }
+            self.memory_length = 0;
+            self.offset = 0;
+            self.ptr = std::ptr::null();
+        }
+    }
+
+    /// A reversed, non-owning view over the memory block of a `ConstRawPtr`.
+    /// 
+    /// `RevView` maps index `i` to element `memory_length - 1 - i` of the underlying block, so a reversed 
+    /// ordering can be passed around without copying the data. The view borrows the `ConstRawPtr` it was 
+    /// created from and never deallocates.
+    pub struct RevView<'a, T> 
+    where  T: Sized + Copy + Send + Sync
+    {
+        base: &'a ConstRawPtr<T>,
+    }
+
+    impl<'a, T: Sized + Copy + Send + Sync> RevView<'a, T> {
+        /// Reads the element at `index` of the reversed view, if valid.
+        /// 
+        /// Index `0` is the last element of the underlying memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let last = ptr.rev_view().read_at(0).unwrap();
+        /// ```
+        pub fn read_at(&self, index: usize) -> Option<T> {
+            if !self.base.check_ptr() || index >= self.base.memory_length {
+                return None;
+            }
+            Some( unsafe { *self.base.ptr.add(self.base.memory_length - 1 - index) } )
+        }
+
+        /// Returns an iterator over the elements in reversed order.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let reversed: Vec<i32> = ptr.rev_view().iter().collect();
+        /// ```
+        pub fn iter(&self) -> impl Iterator<Item = T> + 'a {
+            let base: &'a ConstRawPtr<T> = self.base;
+            let len: usize = if base.check_ptr() { base.memory_length } else { 0 };
+            (0..len).rev().map(move |i| unsafe { *base.ptr.add(i) })
+        }
+    }
+
+    /// An iterator over the elements of a `ConstRawPtr`, created by `ConstRawPtr::iter`.
+    /// 
+    /// `ConstRawPtrIter` yields elements by value from both ends and never reads outside `0..memory_length`. 
+    /// It borrows the `ConstRawPtr` it was created from and never deallocates.
+    pub struct ConstRawPtrIter<'a, T> 
+    where  T: Sized + Copy + Send + Sync
+    {
+        base: &'a ConstRawPtr<T>,
+        front: usize,
+        back: usize,
+    }
+
+    impl<'a, T: Sized + Copy + Send + Sync> Iterator for ConstRawPtrIter<'a, T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            if self.front >= self.back {
+                return None;
+            }
+            let value: T = unsafe { *self.base.ptr.add(self.front) };
+            self.front += 1;
+            Some(value)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining: usize = self.back - self.front;
+            (remaining, Some(remaining))
+        }
+    }
+
+    impl<'a, T: Sized + Copy + Send + Sync> DoubleEndedIterator for ConstRawPtrIter<'a, T> {
+        fn next_back(&mut self) -> Option<T> {
+            if self.front >= self.back {
+                return None;
+            }
+            self.back -= 1;
+            Some( unsafe { *self.base.ptr.add(self.back) } )
+        }
+    }
+
+    impl<'a, T: Sized + Copy + Send + Sync> ExactSizeIterator for ConstRawPtrIter<'a, T> {}
+
+    /// A non-owning `ConstRawPtr` view that borrows the memory it was created from.
+    /// 
+    /// `BorrowedConstRawPtr` is returned by `ConstRawPtr::fork`, `ConstRawPtr::split_at`, `ConstRawPtr::from_ref`, 
+    /// `ConstRawPtr::cast_ptr` and `ConstRawPtr::reinterpret`. It has its own offset and memory length but cannot 
+    /// outlive the memory it borrows, and it never deallocates. Like `BorrowedMutRawPtr`, it only forwards the 
+    /// methods that can't hand out a handle outliving the borrow.
+    pub struct BorrowedConstRawPtr<'a, T> 
+    where  T: Sized + Copy + Send + Sync
+    {
+        inner: ConstRawPtr<T>,
+        _borrow: std::marker::PhantomData<&'a ConstRawPtr<T>>,
+    }
+
+    impl<'a, T: Sized + Copy + Send + Sync> BorrowedConstRawPtr<'a, T> {
+        /* Wraps a non-owned pointer into the memory block borrowed for 'a */
+        fn new(inner: ConstRawPtr<T>) -> Self {
+            Self { inner, _borrow: std::marker::PhantomData }
+        }
+
+        /// Returns the current offset of the view. See `ConstRawPtr::check_offset`.
+        pub fn check_offset(&self) -> usize {
+            self.inner.check_offset()
+        }
+
+        /// Returns the memory length of the view. See `ConstRawPtr::check_memory_length`.
+        pub fn check_memory_length(&self) -> usize {
+            self.inner.check_memory_length()
+        }
+
+        /// Checks if the offset of the view is within bounds. See `ConstRawPtr::check_bounds`.
+        pub fn check_bounds(&self) -> bool {
+            self.inner.check_bounds()
+        }
+
+        /// Changes the offset of the view by a given index, if the resulting offset is within bounds. See `ConstRawPtr::change_offset`.
+        pub fn change_offset(&mut self, index: isize) -> Option<()> {
+            self.inner.change_offset(index)
+        }
+
+        /// Sets the offset of the view, if it is within bounds. See `ConstRawPtr::set_offset`.
+        pub fn set_offset(&mut self, index: usize) -> Option<()> {
+            self.inner.set_offset(index)
+        }
+
+        /// Reads the element at the offset of the view. See `ConstRawPtr::access`.
+        pub fn access(&self) -> Option<T> {
+            self.inner.access()
+        }
+
+        /// Reads the element at `index` of the view without moving its offset. See `ConstRawPtr::read_at`.
+        pub fn read_at(&self, index: usize) -> Option<T> {
+            self.inner.read_at(index)
+        }
+
+        /// Returns the number of elements from the offset to the end of the view. See `ConstRawPtr::remaining`.
+        pub fn remaining(&self) -> usize {
+            self.inner.remaining()
+        }
+
+        /// Copies the elements from the offset to the end of the view into a `Vec<T>`. See `ConstRawPtr::remaining_to_vec`.
+        pub fn remaining_to_vec(&self) -> Option<Vec<T>> {
+            self.inner.remaining_to_vec()
+        }
+
+        /// Reads the first element of the view. See `ConstRawPtr::first`.
+        pub fn first(&self) -> Option<T> {
+            self.inner.first()
+        }
+
+        /// Reads the last element of the view. See `ConstRawPtr::last`.
+        pub fn last(&self) -> Option<T> {
+            self.inner.last()
+        }
+
+        /// Views the elements of the view as a slice. See `ConstRawPtr::as_slice`.
+        pub fn as_slice(&self) -> Option<&[T]> {
+            self.inner.as_slice()
+        }
+
+        /// Copies the elements of the view into a `Vec<T>`. See `ConstRawPtr::to_vec`.
+        pub fn to_vec(&self) -> Option<Vec<T>> {
+            self.inner.to_vec()
+        }
+
+        /// Checks if the view and `other` point to the same element, by address. See `ConstRawPtr::ptr_eq`.
+        pub fn ptr_eq(&self, other: &ConstRawPtr<T>) -> bool {
+            self.inner.ptr_eq(other)
+        }
+    }
+
+    impl ConstRawPtr<u8> {
+        /// Returns a `std::io::Read` implementation over the bytes of the memory block.
+        /// 
+        /// Reads start at the first byte of the block and advance an internal position that is bounded by 
+        /// the memory length, so the reader never reads past the block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let mut buf: Vec<u8> = Vec::new();
+        /// ptr.as_reader().unwrap().read_to_end(&mut buf).unwrap();
+        /// ```
+        pub fn as_reader(&self) -> Option<impl std::io::Read + '_> {
+            if !self.check_ptr() {
+                return None;
+            }
+            let bytes: &[u8] = unsafe { std::slice::from_raw_parts(self.ptr, self.memory_length) };
+            Some(std::io::Cursor::new(bytes))
+        }
+
+        /// Formats the bytes of the memory block as a lowercase hexadecimal string.
+        /// 
+        /// Every byte is written as two hex digits with no separators, in memory order. Unlike 
+        /// `memory_address`, this describes the contents of the block rather than its location.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let hex = ptr.to_hex_string().unwrap();
+        /// ```
+        pub fn to_hex_string(&self) -> Option<String> {
+            use std::fmt::Write;
+
+            if !self.check_ptr() {
+                return None;
+            }
+            let bytes: &[u8] = unsafe { std::slice::from_raw_parts(self.ptr, self.memory_length) };
+            let mut hex: String = String::with_capacity(bytes.len() * 2);
+            for byte in bytes {
+                write!(hex, "{:02x}", byte).ok()?;
+            }
+            Some(hex)
+        }
+
+        /// Counts the set bits across every byte of the memory block.
+        /// 
+        /// This method is useful when the memory block represents a bitmap.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let set_bits = ptr.count_ones().unwrap();
+        /// ```
+        pub fn count_ones(&self) -> Option<u64> {
+            if !self.check_ptr() {
+                return None;
+            }
+            let bytes: &[u8] = unsafe { std::slice::from_raw_parts(self.ptr, self.memory_length) };
+            Some(bytes.iter().map(|byte| byte.count_ones() as u64).sum())
+        }
+
+        /// Counts how often each byte value occurs in the memory block.
+        /// 
+        /// The returned array is indexed by byte value, so `histogram[b]` is the number of bytes equal to `b`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let histogram: [usize; 256] = ptr.histogram().unwrap();
+        /// ```
+        pub fn histogram(&self) -> Option<[usize; 256]> {
+            if !self.check_ptr() {
+                return None;
+            }
+            let mut histogram: [usize; 256] = [0; 256];
+            for i in 0..self.memory_length {
+                histogram[unsafe { *self.ptr.add(i) } as usize] += 1;
+            }
+            Some(histogram)
+        }
+
+        /// Estimates the Shannon entropy of the memory block in bits per byte.
+        /// 
+        /// The entropy is computed from the byte frequency distribution returned by `histogram`, ranging from 
+        /// `0.0` for a block of identical bytes to `8.0` for a uniform distribution.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let entropy: f64 = ptr.shannon_entropy().unwrap();
+        /// ```
+        pub fn shannon_entropy(&self) -> Option<f64> {
+            let histogram: [usize; 256] = self.histogram()?;
+            let total: f64 = self.memory_length as f64;
+            let entropy: f64 = histogram.iter()
+                .filter(|&&count| count > 0)
+                .map(|&count| {
+                    let p: f64 = count as f64 / total;
+                    -p * p.log2()
+                })
+                .sum();
+            Some(entropy)
+        }
+
+        /// Returns an iterator over the bytes of the memory block interpreted as consecutive `T` records.
+        /// 
+        /// Records are read with `std::ptr::read_unaligned`, so the byte block does not need to be aligned for 
+        /// `T`. The iterator yields `memory_length / size_of::<T>()` records; trailing bytes that do not form a 
+        /// whole record are ignored.
+        /// 
+        /// # Safety
+        /// 
+        /// The bytes of every record must be initialized and form a valid value of `T`. Types valid for any bit 
+        /// pattern, such as integers, always satisfy this; `bool`, `char` and enums generally don't.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let values: Vec<u16> = unsafe { ptr.records::<u16>() }.unwrap().collect();
+        /// ```
+        pub unsafe fn records<T: Copy>(&self) -> Option<impl Iterator<Item = T> + '_> {
+            let size: usize = std::mem::size_of::<T>();
+            if !self.check_ptr() || size == 0 {
+                return None;
+            }
+            let count: usize = self.memory_length / size;
+            Some((0..count).map(move |i| unsafe { std::ptr::read_unaligned(self.ptr.add(i * size) as *const T) }))
+        }
+
+        /// Returns the length of the C string starting at the current offset, excluding the NUL terminator.
+        /// 
+        /// Unlike `nul_terminated_len`, a string that isn't terminated within the memory length is rejected 
+        /// rather than measured up to the end of the block.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(usize)`: The number of bytes before the first NUL byte.
+        /// - `None`: If the pointer is invalid, the offset is out of bounds, or no NUL byte is found before the 
+        ///   end of the memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let len: usize = c_str.c_strlen().unwrap();
+        /// ```
+        pub fn c_strlen(&self) -> Option<usize> {
+            let len: usize = self.nul_terminated_len()?;
+            if self.offset + len == self.memory_length {
+                return None;
+            }
+            Some(len)
+        }
+
+        /// Views the C string starting at the current offset as a `CStr`.
+        /// 
+        /// The string runs up to and including the first NUL byte, which must lie within the memory length.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(&CStr)`: The string at the current offset.
+        /// - `None`: If the pointer is invalid, the offset is out of bounds, or the string isn't NUL terminated 
+        ///   within the memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let name: &std::ffi::CStr = c_str.as_cstr().unwrap();
+        /// ```
+        pub fn as_cstr(&self) -> Option<&std::ffi::CStr> {
+            let len: usize = self.c_strlen()?;
+            let bytes: &[u8] = unsafe { std::slice::from_raw_parts(self.ptr.add(self.offset), len + 1) };
+            std::ffi::CStr::from_bytes_with_nul(bytes).ok()
+        }
+
+        /// Copies the C string starting at the current offset into a `String`.
+        /// 
+        /// Invalid UTF-8 sequences are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(String)`: The decoded string.
+        /// - `None`: If `as_cstr` would return `None`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let name: String = c_str.to_string_lossy_owned().unwrap();
+        /// ```
+        pub fn to_string_lossy_owned(&self) -> Option<String> {
+            Some(self.as_cstr()?.to_string_lossy().into_owned())
+        }
+    }
+}
+
+pub mod mut_raw_ptr {
+    pub struct MutRawPtr<T> 
+    where  T: Sized + Copy + Send + Sync
+    {
+        pub(crate) ptr: *mut T,
+        pub(crate) memory_length: usize,
+        pub(crate) capacity: usize,
+        pub(crate) offset: usize,
+        pub(crate) owned: bool,
+        pub(crate) align: usize,
+    }
+
+    impl<T: Sized + Copy + Send + Sync> MutRawPtr<T> {
+        /// Allocates memory for an array of `memory_length` elements of type `T` and returns an owning `MutRawPtr` to it.
+        ///
+        /// The returned pointer owns the allocation, so the memory block is deallocated when it is dropped.
+        ///
+        /// # Parameters
+        ///
+        /// - `memory_length`: The number of elements of type `T` to allocate memory for. Must be greater than 0.
+        /// - `offset`: The initial offset of the pointer. Must be within the bounds of `memory_length`.
+        ///
+        /// # Returns
+        ///
+        /// - `Some(MutRawPtr<T>)`: An owning pointer to the allocated memory if successful.
+        /// - `None`: If `memory_length` is 0, the offset is out of bounds, or the allocation failed.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if the alignment or size parameters are invalid. This includes:
+        /// - Alignment being zero or not a power of two.
+        /// - The size, when rounded up to the nearest multiple of alignment, overflows `isize` (i.e., the rounded value must be less than or equal to `isize::MAX`).
+        ///
+        /// # Safety
+        ///
+        /// The allocated memory is not initialized. The caller must write every element before reading it, including 
+        /// through safe methods such as `access`, `as_slice` or `iter`.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// let _: MutRawPtr<i32> = unsafe { MutRawPtr::c_malloc(1, 0).unwrap() };
+        /// ```
+        pub unsafe fn c_malloc(memory_length: usize, offset: usize) -> Option<Self> {
+            if memory_length <= 0 || offset >= memory_length {
+                return None;
+            }
+
+            let size: usize = std::mem::size_of::<T>() * memory_length;
+            let align: usize = std::mem::align_of::<T>();
+            let layout: std::alloc::Layout = std::alloc::Layout::from_size_align(size, align).expect("Invalid alignment or size parameters.");
+
+            unsafe {
+                let alloc: *mut T = std::alloc::alloc(layout) as *mut T;
+                if alloc.is_null() {
+                    return None;
+                }
+                #[cfg(feature = "debug-trace")]
+                super::debug_trace::record(alloc as usize, memory_length);
+                return Some(Self { ptr: alloc, memory_length, capacity: memory_length, offset, owned: true, align: std::mem::align_of::<T>() });
+            } 
+        }
+
+        /// Allocates memory for an array of `memory_length` zeroed elements of type `T` and returns an owning `MutRawPtr` to it.
+        ///
+        /// # Safety
+        ///
+        /// Every element starts out as all-zero bytes, so this is only valid for types where an all-zero bit 
+        /// pattern is a valid value of `T` (e.g. integers and floats, but not references or `NonNull`).
+        ///
+        /// # Returns
+        ///
+        /// - `Some(MutRawPtr<T>)`: An owning pointer to the zeroed memory if successful.
+        /// - `None`: If `memory_length` is 0, the offset is out of bounds, or the allocation failed.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// let zeroed: MutRawPtr<u64> = unsafe { MutRawPtr::c_calloc(10, 0).unwrap() };
+        /// ```
+        pub unsafe fn c_calloc(memory_length: usize, offset: usize) -> Option<Self> {
+            if memory_length == 0 || offset >= memory_length {
+                return None;
+            }
+            let layout: std::alloc::Layout = std::alloc::Layout::array::<T>(memory_length).ok()?;
+            let alloc: *mut T = std::alloc::alloc_zeroed(layout) as *mut T;
+            if alloc.is_null() {
+                return None;
+            }
+            #[cfg(feature = "debug-trace")]
+            super::debug_trace::record(alloc as usize, memory_length);
+            Some(Self { ptr: alloc, memory_length, capacity: memory_length, offset, owned: true, align: std::mem::align_of::<T>() })
+        }
+
+        /// Allocates memory for `memory_length` elements of type `T` aligned to `align` bytes, copies `data` into it, and 
+        /// returns an owning `MutRawPtr` to it.
+        ///
+        /// This is useful for over-aligned buffers, such as cache-line aligned data for SIMD. The alignment is stored 
+        /// so the memory block is deallocated with a matching layout on drop.
+        ///
+        /// # Safety
+        ///
+        /// Only the first `data.len()` elements are initialized. The caller must write the rest before reading them.
+        ///
+        /// # Returns
+        ///
+        /// - `Some(MutRawPtr<T>)`: An owning pointer to the aligned memory if successful.
+        /// - `None`: If `align` is not a power of two or is smaller than `align_of::<T>()`, `memory_length` is 0, 
+        ///   `data` is longer than `memory_length`, the offset is out of bounds, or the allocation failed.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// let simd: MutRawPtr<f32> = unsafe { MutRawPtr::c_malloc_aligned(vec![0.0; 16], 16, 0, 64).unwrap() };
+        /// ```
+        pub unsafe fn c_malloc_aligned(data: Vec<T>, memory_length: usize, offset: usize, align: usize) -> Option<Self> {
+            if !align.is_power_of_two() || align < std::mem::align_of::<T>() {
+                return None;
+            }
+            if memory_length == 0 || data.len() > memory_length || offset >= memory_length {
+                return None;
+            }
+            let size: usize = std::mem::size_of::<T>().checked_mul(memory_length)?;
+            let layout: std::alloc::Layout = std::alloc::Layout::from_size_align(size, align).ok()?;
+            let alloc: *mut T = std::alloc::alloc(layout) as *mut T;
+            if alloc.is_null() {
+                return None;
+            }
+            std::ptr::copy_nonoverlapping(data.as_ptr(), alloc, data.len());
+            #[cfg(feature = "debug-trace")]
+            super::debug_trace::record(alloc as usize, memory_length);
+            Some(Self { ptr: alloc, memory_length, capacity: memory_length, offset, owned: true, align })
+        }
+
+        /// Allocates a new memory block with `c_malloc` and copies `data` into it.
+        /// 
+        /// Returns `None` if `data` is empty. The offset of the returned pointer starts at the first element.
+        pub(crate) fn c_malloc_from_slice(data: &[T]) -> Option<Self> {
+            let block: Self = unsafe { Self::c_malloc(data.len(), 0) }?;
+            unsafe { std::ptr::copy_nonoverlapping(data.as_ptr(), block.ptr, data.len()) };
+            Some(block)
+        }
+
+        /// Creates a new `MutRawPtr` with the given pointer, memory length, and offset.
+        /// 
+        /// This method ensures that the pointer is properly aligned and that the offset is within the bounds 
+        /// of the allocated memory length.
+        /// 
+        /// The returned pointer does not own the memory block, so it is never deallocated on drop. Call 
+        /// `set_owned(true)` if the memory was allocated by this library's allocator and should be freed on drop.
+        /// 
+        /// # Panics
+        /// 
+        /// Panics if the pointer is not aligned to `T` or if the offset is not within the bounds of the memory length.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let alloc_ptr: *mut i32 = ...; // Assume this is a properly allocated and aligned pointer either from C or using Rust's std::alloc::alloc and std::alloc::Layout otherwise it will panic.
+        /// let ptr = MutRawPtr::new(alloc_ptr, 1, 0);
+        /// ```
+        #[inline]
+        pub fn new(ptr: *mut T, memory_length: usize, offset: usize) -> Self {
+            Self::try_new(ptr, memory_length, offset).unwrap_or_else(|err| panic!("{}", err))
+        }
+
+        /// Creates a new `MutRawPtr` like `new`, returning an error instead of panicking.
+        /// 
+        /// This is useful for validating pointers received from C at runtime. The returned pointer does not own 
+        /// the memory block.
+        /// 
+        /// # Errors
+        /// 
+        /// - `BoxRawPtrError::Misaligned`: If the pointer is not aligned to `T`.
+        /// - `BoxRawPtrError::OutOfBounds`: If the offset is not within the bounds of the memory length.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let ptr = MutRawPtr::try_new(alloc_ptr, 1, 0)?;
+        /// ```
+        pub fn try_new(ptr: *mut T, memory_length: usize, offset: usize) -> Result<Self, super::error::BoxRawPtrError> {
+            if !(ptr as usize).is_multiple_of(std::mem::align_of::<T>()) {
+                return Err(super::error::BoxRawPtrError::Misaligned);
+            }
+            if offset >= memory_length {
+                return Err(super::error::BoxRawPtrError::OutOfBounds);
+            }
+            Ok(Self { ptr, memory_length, capacity: memory_length, offset, owned: false, align: std::mem::align_of::<T>() })
+        }
+
+        /// Creates a new `MutRawPtr` with the given pointer, memory length, and offset, without requiring alignment.
+        /// 
+        /// This is meant for packed or deliberately unaligned C data. Elements of such a pointer must be read 
+        /// with `access_unaligned` and written with `write_unaligned`; the aligned methods check alignment and return `None`. Mixing 
+        /// `new_unaligned` with `ref_const`/`ref_mut` is still undefined behavior since references require alignment.
+        /// The returned pointer does not own the memory block.
+        /// 
+        /// # Panics
+        /// 
+        /// Panics if the offset is not within the bounds of the memory length.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let packed_ptr: *mut u32 = ...; // Assume this points into a packed C structure
+        /// let ptr = MutRawPtr::new_unaligned(packed_ptr, 1, 0);
+        /// ```
+        #[inline]
+        pub fn new_unaligned(ptr: *mut T, memory_length: usize, offset: usize) -> Self {
+            assert!(offset < memory_length, "box_raw_ptr Err: Offset Is Not Within Bounds");
+            Self { ptr, memory_length, capacity: memory_length, offset, owned: false, align: std::mem::align_of::<T>() }
+        }
+
+        /// Creates a new `MutRawPtr` with a null mutable pointer and zero memory length and offset.
+        /// 
+        /// This is useful for creating a placeholder `MutRawPtr` that can later be assigned a valid mutable pointer.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let null_ptr = MutRawPtr::<i32>::nullptr();
+        /// ```
+        #[inline]
+        pub fn nullptr() -> Self {
+            Self { ptr: std::ptr::null_mut(), memory_length: 0, capacity: 0, offset: 0, owned: false, align: std::mem::align_of::<T>() }
+        }
+
+        /// Manually drops the `MutRawPtr` instance.
+        /// 
+        /// # Safety
+        /// 
+        /// This function is unsafe because it drops the instance manually, which can lead to undefined behavior 
+        /// if the instance is used after being dropped.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// unsafe {
+        ///     mut_ptr.manual_drop();
+        /// }
+        /// ```
+        #[inline]
+        pub unsafe fn manual_drop(self) -> () {
+            drop(self);
+        }
+
+        /// Checks if the current offset is within the bounds of the memory length.
+        /// 
+        /// This method ensures that the mutable pointer is pointing to a valid position within the allocated memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(mut_ptr.check_bounds());
+        /// ```
+        #[inline]
+        pub fn check_bounds(&self) -> bool {
+            self.offset < self.memory_length
+        }
+
+        /// Checks if the mutable pointer is not null and properly aligned.
+        /// 
+        /// This method ensures that the mutable pointer is valid and meets the alignment requirements of `T`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(mut_ptr.check_ptr());
+        /// ```
+        pub fn check_ptr(&self) -> bool {
+            if self.ptr.is_null() {
+                return false;
+            }
+            let align: usize = std::mem::align_of::<T>();
+            (self.ptr as usize) % align == 0
+        }
+
+        /// Returns the current offset.
+        /// 
+        /// This method provides the current offset within the memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let offset = mut_ptr.check_offset();
+        /// ```
+        pub fn check_offset(&self) -> usize {
+            self.offset
+        }
+
+        /// Returns the current memory length.
+        /// 
+        /// This method provides the total length of the memory block that the mutable pointer points to.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let length = mut_ptr.check_memory_length();
+        /// ```
+        pub fn check_memory_length(&self) -> usize {
+            self.memory_length
+        }
+
+        /// Returns the current capacity.
+        /// 
+        /// This method provides the number of elements the allocation behind the mutable pointer can hold, which is 
+        /// never less than the memory length.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let capacity = mut_ptr.check_capacity();
+        /// ```
+        pub fn check_capacity(&self) -> usize {
+            self.capacity
+        }
+
+        /// Changes the offset by a given index, if the resulting offset is within bounds.
+        /// 
+        /// This method allows you to move the mutable pointer by a specified index within the memory block, 
+        /// ensuring that the new offset is within bounds.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(mut_ptr.change_offset(2).is_some());
+        /// ```
+        pub fn change_offset(&mut self, index: isize) -> Option<()> {
+            self.try_change_offset(index).ok()
+        }
+
+        /// Moves the offset by `count` elements using checked arithmetic.
+        /// 
+        /// The new offset is validated against `0..memory_length` before anything is changed, and no pointer 
+        /// arithmetic is performed, so a pathological `count` such as `isize::MAX` returns `None` instead of 
+        /// overflowing. This is the same check `change_offset` performs.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the offset was moved.
+        /// - `None`: If the pointer is invalid or the resulting offset is out of bounds or overflows.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(ptr.checked_offset(isize::MAX).is_none());
+        /// ```
+        pub fn checked_offset(&mut self, count: isize) -> Option<()> {
+            self.try_change_offset(count).ok()
+        }
+
+        /// Changes the memory length, if the new length is valid.
+        /// 
+        /// The new length may not exceed the capacity of the allocation, so the memory length can never outgrow 
+        /// the memory block that is deallocated on drop. Shrinking leaves the capacity, and therefore the layout 
+        /// used on drop, unchanged. The capacity must match the true allocation, so pointers adopted with the 
+        /// wrong length through `new` should be corrected with `with_corrected_length` first.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the memory length was changed.
+        /// - `None`: If `memory_length` is 0, exceeds the capacity, or the current offset would fall outside it.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(mut_ptr.change_memory_length(10).is_some());
+        /// ```
+        pub fn change_memory_length(&mut self, memory_length: usize) -> Option<()> {
+            if memory_length == 0 || memory_length > self.capacity || self.offset >= memory_length {
+                return None;
+            }
+
+            self.memory_length = memory_length;
+            Some(())
+        }
+
+        /// Releases the mutable pointer and returns the value it points to, if valid.
+        /// 
+        /// This method takes ownership of the mutable pointer and returns the value it points to, ensuring that 
+        /// the pointer is valid and properly aligned.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let value = mut_ptr.release_ptr().unwrap();
+        /// ```
+        pub fn release_ptr(self) -> Option<T> {
+            if self.check_ptr() {
+                unsafe {
+                    let ptr: T = *self.ptr;
+                    drop(self);
+                    Some(ptr)
+                }
+            } else {
+                None
+            }
+        }
+
+        /// Sets the mutable pointer to null and resets the memory length and offset.
+        /// 
+        /// This method is useful for invalidating a mutable pointer and ensuring that it no longer points to any memory.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.set_null();
+        /// ```
+        #[inline]
+        pub fn set_null(&mut self) -> () {
+            if self.check_ptr() {
+                self.memory_length = 0;
+                self.offset = 0;
+                self.ptr = std::ptr::null_mut();
+            }
+        }
+
+        /// Returns the memory address of the mutable pointer as a hexadecimal string.
+        /// 
+        /// This method is useful for debugging and logging purposes to inspect the raw memory address.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let address = mut_ptr.memory_address();
+        /// ```
+        #[inline]
+        pub fn memory_address(&self) -> String {
+            format!("{:x}", self.ptr as usize)
+        }
+
+        /// Converts the `MutRawPtr` to a constant pointer (`ConstRawPtr`).
+        /// 
+        /// This method creates a constant version of the `MutRawPtr`, which allows for read-only access to the 
+        /// underlying data.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let const_ptr = mut_ptr.as_const();
+        /// ```
+        pub fn as_const(&self) -> super::const_raw_ptr::ConstRawPtr<T> {
+            super::const_raw_ptr::ConstRawPtr::new(self.ptr as *const T, self.memory_length, self.offset)
+        }
+
+        /// Unwraps the mutable pointer and returns the value it points to, if valid.
+        /// 
+        /// This method returns the value that the mutable pointer points to, ensuring that the pointer is valid and 
+        /// properly aligned.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let value = mut_ptr.unwrap().unwrap();
+        /// ```
+        pub fn unwrap(self) -> Option<T> {
+            if self.check_ptr() {
+                Some( unsafe { *self.ptr } )
+            } else {
+                None
+            }
+        }
+
+        /// Returns a reference to the value the mutable pointer points to, if valid.
+        /// 
+        /// This method provides a reference to the value that the mutable pointer points to, ensuring that the pointer 
+        /// is valid and properly aligned.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let reference = mut_ptr.ref_const().unwrap();
+        /// ```
+        pub fn ref_const(&self) -> Option<&T> {
+            if self.check_ptr() {
+                Some( unsafe { & *self.ptr } )
+            } else {
+                None
+            }
+        }
+
+        /// Returns a mutable reference to the value the mutable pointer points to, if valid.
+        /// 
+        /// This method provides a mutable reference to the value that the mutable pointer points to, ensuring that 
+        /// the pointer is valid and properly aligned. It takes `&mut self` so that a shared `MutRawPtr`, which is 
+        /// `Sync`, can't hand out aliasing mutable references.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let mut reference = mut_ptr.ref_mut().unwrap();
+        /// *reference = 42;
+        /// ```
+        pub fn ref_mut(&mut self) -> Option<&mut T> {
+            if self.check_ptr() {
+                unsafe { Some(&mut *self.ptr) }
+            } else {
+                None
+            }
+        }
+
+        /// Checks if the mutable pointer is null.
+        /// 
+        /// This method determines if the mutable pointer is null, which is useful for validation and error checking.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(mut_ptr.is_null());
+        /// ```
+        #[inline]
+        pub fn is_null(&self) -> bool {
+            self.ptr.is_null()
+        }
+
+        /// Returns the size of the type `T`.
+        /// 
+        /// This method provides the size of the type `T` in bytes, which is useful for memory allocation and 
+        /// pointer arithmetic.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let size = mut_ptr.size_of();
+        /// ```
+        #[inline]
+        pub fn size_of(&self) -> usize {
+            std::mem::size_of::<T>()
+        }
+
+        /// Casts the mutable pointer to a mutable view of another type `U`.
+        /// 
+        /// The memory length, capacity, and offset are rescaled from elements of `T` to elements of `U`, so the 
+        /// view covers the same bytes. The offset points at the element of `U` containing the current element 
+        /// of `T`. The view mutably borrows `self` and never deallocates, so the allocation is still freed only 
+        /// by `self`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(BorrowedMutRawPtr<U>)`: The cast view.
+        /// - `None`: If the pointer is null, is not aligned for `U`, `U` is zero-sized, or the memory block or 
+        ///   capacity is not a whole number of elements of `U`.
+        /// 
+        /// # Safety
+        /// 
+        /// The bytes of the memory block must be initialized and form valid values of `U`, e.g. only `0` or `1` 
+        /// for `bool`. Values written through the view must leave the bytes valid values of `T` once the view is 
+        /// dropped and `self` reads them again.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let mut bytes = unsafe { mut_ptr.cast_ptr::<u8>() }.unwrap();
+        /// ```
+        pub unsafe fn cast_ptr<U: Sized + Copy + Send + Sync>(&mut self) -> Option<BorrowedMutRawPtr<'_, U>> {
+            let size: usize = std::mem::size_of::<T>();
+            let new_size: usize = std::mem::size_of::<U>();
+            if self.ptr.is_null() || new_size == 0 || !(self.ptr as usize).is_multiple_of(std::mem::align_of::<U>()) {
+                return None;
+            }
+            let bytes: usize = size.checked_mul(self.memory_length)?;
+            let capacity_bytes: usize = size.checked_mul(self.capacity)?;
+            if !bytes.is_multiple_of(new_size) || !capacity_bytes.is_multiple_of(new_size) {
+                return None;
+            }
+            Some(BorrowedMutRawPtr::new(MutRawPtr {
+                ptr: self.ptr as *mut U,
+                memory_length: bytes / new_size,
+                capacity: capacity_bytes / new_size,
+                offset: size * self.offset / new_size,
+                owned: false,
+                align: self.align,
+            }))
+        }
+
+        /// Writes a value into the memory location pointed to by the mutable pointer.
+        /// 
+        /// This method writes a value into the element at the current offset, ensuring that the pointer is 
+        /// valid, properly aligned, and in bounds. See `try_write_ptr` for the reason of a failure.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.write_ptr(42);
+        /// ```
+        pub fn write_ptr(&mut self, src: T) -> Option<()> {
+            self.try_write_ptr(src).ok()
+        }
+
+        /// XORs each element with the corresponding element of another memory block in place.
+        /// 
+        /// This method operates over the shorter of the two memory lengths, leaving any remaining elements 
+        /// untouched. Applying the same block twice restores the original data.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.xor_with(&key).unwrap();
+        /// ```
+        pub fn xor_with(&mut self, other: &super::const_raw_ptr::ConstRawPtr<T>) -> Option<()>
+        where T: std::ops::BitXor<Output = T>
+        {
+            if !self.check_ptr() || !other.check_ptr() {
+                return None;
+            }
+            let len: usize = self.memory_length.min(other.memory_length);
+            for i in 0..len {
+                unsafe {
+                    *self.ptr.add(i) = *self.ptr.add(i) ^ *other.ptr.add(i);
+                }
+            }
+            Some(())
+        }
+
+        /// Replaces each element with the maximum of itself and every element before it.
+        /// 
+        /// This method is the maximum analog of a prefix sum and operates over the whole memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.running_max().unwrap();
+        /// ```
+        pub fn running_max(&mut self) -> Option<()>
+        where T: Ord
+        {
+            if !self.check_ptr() {
+                return None;
+            }
+            for i in 1..self.memory_length {
+                unsafe {
+                    let prev: T = *self.ptr.add(i - 1);
+                    let elem: *mut T = self.ptr.add(i);
+                    *elem = std::cmp::max(prev, *elem);
+                }
+            }
+            Some(())
+        }
+
+        /// Resizes the memory block to `new_length` elements, preserving the current offset where possible.
+        /// 
+        /// The existing prefix is preserved, any newly added elements are set to `fill`, and the offset is 
+        /// clamped to the new memory length if it no longer fits. Only owned memory blocks (e.g. from `c_malloc`) 
+        /// are resized, since a borrowed block may live on the stack or belong to another allocator.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(true)`: The block was resized and the offset had to be clamped.
+        /// - `Some(false)`: The block was resized and the offset was preserved.
+        /// - `None`: If the pointer is invalid or not owned, `new_length` is 0, or the reallocation failed.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let clamped = mut_ptr.resize_preserving_offset(10, 0).unwrap();
+        /// ```
+        pub fn resize_preserving_offset(&mut self, new_length: usize, fill: T) -> Option<bool> {
+            if !self.owned || !self.check_ptr() || new_length == 0 {
+                return None;
+            }
+            let layout: std::alloc::Layout = self.layout()?;
+            let new_size: usize = std::alloc::Layout::array::<T>(new_length).ok()?.size();
+
+            let ptr: *mut T = unsafe { std::alloc::realloc(self.ptr as *mut u8, layout, new_size) as *mut T };
+            if ptr.is_null() {
+                return None;
+            }
+            #[cfg(feature = "debug-trace")]
+            {
+                super::debug_trace::forget(self.ptr as usize);
+                super::debug_trace::record(ptr as usize, new_length);
+            }
+            for i in self.memory_length..new_length {
+                unsafe { std::ptr::write(ptr.add(i), fill) };
+            }
+
+            self.ptr = ptr;
+            self.memory_length = new_length;
+            self.capacity = new_length;
+            let clamped: bool = self.offset >= new_length;
+            if clamped {
+                self.offset = new_length - 1;
+            }
+            Some(clamped)
+        }
+
+        /// Removes consecutive duplicate elements in place, mirroring `Vec::dedup`.
+        /// 
+        /// Surviving elements are compacted toward the start of the memory block and the memory length is 
+        /// updated to the number of survivors, leaving the capacity unchanged. The offset is clamped to the 
+        /// new memory length if needed.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let new_length = mut_ptr.dedup().unwrap();
+        /// ```
+        pub fn dedup(&mut self) -> Option<usize>
+        where T: PartialEq
+        {
+            if !self.check_ptr() || self.memory_length == 0 {
+                return None;
+            }
+            let mut len: usize = 1;
+            for i in 1..self.memory_length {
+                unsafe {
+                    let elem: T = *self.ptr.add(i);
+                    if elem != *self.ptr.add(len - 1) {
+                        *self.ptr.add(len) = elem;
+                        len += 1;
+                    }
+                }
+            }
+            self.memory_length = len;
+            self.offset = self.offset.min(len - 1);
+            Some(len)
+        }
+
+        /// Converts the `MutRawPtr` into a fixed-capacity FIFO ring buffer over the same memory block.
+        /// 
+        /// The ring starts empty and can hold `memory_length` elements. See `RingRawPtr` for details.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let mut ring = mut_ptr.into_ring();
+        /// ```
+        #[inline]
+        pub fn into_ring(self) -> super::ring_raw_ptr::RingRawPtr<T> {
+            super::ring_raw_ptr::RingRawPtr::new(self)
+        }
+
+        /// Projects a field of the current element as its own mutable view.
+        /// 
+        /// The returned view addresses the field located `byte_offset` bytes into the current element and has 
+        /// a memory length of 1. It mutably borrows `self` and never deallocates, so dropping it leaves the outer 
+        /// memory block intact. `byte_offset` is typically obtained with `std::mem::offset_of!`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(BorrowedMutRawPtr<U>)`: A view of the field.
+        /// - `None`: If the pointer is invalid, the field does not fit within `T`, or the field address is not 
+        ///   aligned for `U`.
+        /// 
+        /// # Safety
+        /// 
+        /// `byte_offset` must be the offset of a field of `T` whose type is `U`, e.g. from `std::mem::offset_of!`. 
+        /// Any other offset may read padding or write bits that are invalid for the fields it overlaps.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let mut field = unsafe { mut_ptr.project::<f64>(std::mem::offset_of!(Data, b)) }.unwrap();
+        /// ```
+        pub unsafe fn project<U: Sized + Copy + Send + Sync>(&mut self, byte_offset: usize) -> Option<BorrowedMutRawPtr<'_, U>> {
+            if !self.check_ptr() || !self.check_bounds() {
+                return None;
+            }
+            if byte_offset.checked_add(std::mem::size_of::<U>())? > std::mem::size_of::<T>() {
+                return None;
+            }
+            let field: *mut U = (self.ptr.add(self.offset) as *mut u8).add(byte_offset) as *mut U;
+            if !(field as usize).is_multiple_of(std::mem::align_of::<U>()) {
+                return None;
+            }
+            Some(BorrowedMutRawPtr::new(MutRawPtr { ptr: field, memory_length: 1, capacity: 1, offset: 0, owned: false, align: std::mem::align_of::<U>() }))
+        }
+
+        /// Inserts a value at `index`, shifting all elements after it up by one.
+        /// 
+        /// The memory length grows by one, which requires spare capacity in the allocation. Inserting at 
+        /// `index == memory_length` appends the value to the end of the memory block.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the value was inserted.
+        /// - `None`: If the pointer is invalid, the memory block is at capacity, or `index > memory_length`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.insert(1, 42).unwrap();
+        /// ```
+        pub fn insert(&mut self, index: usize, value: T) -> Option<()> {
+            if !self.check_ptr() || self.memory_length >= self.capacity || index > self.memory_length {
+                return None;
+            }
+            unsafe {
+                let slot: *mut T = self.ptr.add(index);
+                std::ptr::copy(slot, slot.add(1), self.memory_length - index);
+                std::ptr::write(slot, value);
+            }
+            self.memory_length += 1;
+            Some(())
+        }
+
+        /// Removes and returns the element at `index`, shifting all elements after it down by one.
+        /// 
+        /// The memory length shrinks by one while the capacity is unchanged. The offset is clamped to the new 
+        /// memory length if needed.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let value = mut_ptr.remove(1).unwrap();
+        /// ```
+        pub fn remove(&mut self, index: usize) -> Option<T> {
+            if !self.check_ptr() || index >= self.memory_length {
+                return None;
+            }
+            let value: T = unsafe {
+                let slot: *mut T = self.ptr.add(index);
+                let value: T = std::ptr::read(slot);
+                std::ptr::copy(slot.add(1), slot, self.memory_length - index - 1);
+                value
+            };
+            self.memory_length -= 1;
+            self.offset = self.offset.min(self.memory_length.saturating_sub(1));
+            Some(value)
+        }
+
+        /// Removes and returns the element at `index`, replacing it with the last element.
+        /// 
+        /// This does not preserve ordering, but runs in O(1). The memory length shrinks by one while the 
+        /// capacity is unchanged. The offset is clamped to the new memory length if needed.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let value = mut_ptr.swap_remove(1).unwrap();
+        /// ```
+        pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+            if !self.check_ptr() || index >= self.memory_length {
+                return None;
+            }
+            let value: T = unsafe {
+                let value: T = std::ptr::read(self.ptr.add(index));
+                std::ptr::write(self.ptr.add(index), std::ptr::read(self.ptr.add(self.memory_length - 1)));
+                value
+            };
+            self.memory_length -= 1;
+            self.offset = self.offset.min(self.memory_length.saturating_sub(1));
+            Some(value)
+        }
+
+        /// Creates a non-owning `MutRawPtr` over a mutable array, typically one on the stack.
+        /// 
+        /// The returned `BorrowedMutRawPtr` wraps a `MutRawPtr` with a memory length of `N` and never 
+        /// deallocates. It mutably borrows the array, so it cannot outlive it and avoids a heap allocation for 
+        /// small buffers.
+        /// 
+        /// # Panics
+        /// 
+        /// Panics if `N` is 0, since an empty memory block has no valid offset.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let mut arr: [i32; 4] = [1, 2, 3, 4];
+        /// let mut view = MutRawPtr::from_array_mut(&mut arr);
+        /// view.running_max().unwrap();
+        /// ```
+        /// 
+        /// The view cannot outlive the array it borrows:
+        /// 
+        /// ```compile_fail,E0597
+        /// use box_raw_ptr::mut_raw_ptr::MutRawPtr;
+        /// 
+        /// let view = {
+        ///     let mut arr: [i32; 4] = [1, 2, 3, 4];
+        ///     MutRawPtr::from_array_mut(&mut arr)
+        /// };
+        /// ```
+        /// 
+        /// Nor can it hand out the inner `MutRawPtr`, which has no lifetime:
+        /// 
+        /// ```compile_fail
+        /// use box_raw_ptr::mut_raw_ptr::MutRawPtr;
+        /// 
+        /// let mut arr: [i32; 4] = [1, 2, 3, 4];
+        /// let escaped: MutRawPtr<i32> = (*MutRawPtr::from_array_mut(&mut arr)).clone();
+        /// ```
+        pub fn from_array_mut<const N: usize>(arr: &mut [T; N]) -> BorrowedMutRawPtr<'_, T> {
+            assert!(N > 0, "box_raw_ptr Err: Offset Is Not Within Bounds");
+            let ptr: *mut T = arr.as_mut_ptr();
+            BorrowedMutRawPtr::new(MutRawPtr { ptr, memory_length: N, capacity: N, offset: 0, owned: false, align: std::mem::align_of::<T>() })
+        }
+
+        /// Clamps every element of the memory block into the range `[min, max]` in place.
+        /// 
+        /// This is the in-place analog of mapping `x.clamp(min, max)` over the whole block.
+        /// 
+        /// # Panics
+        /// 
+        /// Panics if `min > max`, matching `Ord::clamp`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.clamp_each(0, 10).unwrap();
+        /// ```
+        pub fn clamp_each(&mut self, min: T, max: T) -> Option<()>
+        where T: Ord
+        {
+            if !self.check_ptr() {
+                return None;
+            }
+            assert!(min <= max, "box_raw_ptr Err: Invalid Clamp Range");
+            for i in 0..self.memory_length {
+                unsafe {
+                    let elem: *mut T = self.ptr.add(i);
+                    *elem = (*elem).clamp(min, max);
+                }
+            }
+            Some(())
+        }
+
+        /// Adds the corresponding elements of another memory block into this one in place.
+        /// 
+        /// This method operates over the shorter of the two memory lengths. Addition uses `wrapping_add`, so 
+        /// an overflowing element wraps around (e.g. `u8::MAX + 1 == 0`) instead of panicking.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.add_blocks(&other).unwrap();
+        /// ```
+        pub fn add_blocks(&mut self, other: &super::const_raw_ptr::ConstRawPtr<T>) -> Option<()>
+        where T: super::integer::Integer
+        {
+            if !self.check_ptr() || !other.check_ptr() {
+                return None;
+            }
+            let len: usize = self.memory_length.min(other.memory_length);
+            for i in 0..len {
+                unsafe {
+                    *self.ptr.add(i) = (*self.ptr.add(i)).wrapping_add(*other.ptr.add(i));
+                }
+            }
+            Some(())
+        }
+
+        /// Recomputes the offset from a pointer into the memory block, such as a cursor advanced by C code.
+        /// 
+        /// The offset is set to the element `current` points at, computed as `(current - base) / size_of::<T>()`. 
+        /// This re-establishes the offset after C code has advanced its own copy of the pointer. `current` is 
+        /// only compared against the base, never dereferenced.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the offset was updated.
+        /// - `None`: If the pointer is invalid, or `current` is not a whole number of elements past the base or 
+        ///   lies outside the memory length. The offset is left unchanged in that case.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.sync_offset_from_base(c_cursor).unwrap();
+        /// ```
+        pub fn sync_offset_from_base(&mut self, current: *mut T) -> Option<()> {
+            let size: usize = std::mem::size_of::<T>();
+            if !self.check_ptr() || size == 0 {
+                return None;
+            }
+            let distance: usize = (current as usize).checked_sub(self.ptr as usize)?;
+            if !distance.is_multiple_of(size) || distance / size >= self.memory_length {
+                return None;
+            }
+            self.offset = distance / size;
+            Some(())
+        }
+
+        /// Writes `f(i)` into every index `i` of the memory block, stopping at the first error.
+        /// 
+        /// Elements written before the failing index are left in place. The offset is not changed.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Ok(())`: If every element was written.
+        /// - `Err(FillError::InvalidPtr)`: If the pointer is invalid. Nothing is written and `f` is never called.
+        /// - `Err(FillError::Source { index, error })`: If `f` failed at `index`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.try_fill_with(|i| source.read(i)).unwrap();
+        /// ```
+        pub fn try_fill_with<F, E>(&mut self, mut f: F) -> Result<(), FillError<E>>
+        where F: FnMut(usize) -> Result<T, E>
+        {
+            if !self.check_ptr() {
+                return Err(FillError::InvalidPtr);
+            }
+            for index in 0..self.memory_length {
+                let value: T = f(index).map_err(|error| FillError::Source { index, error })?;
+                unsafe { std::ptr::write(self.ptr.add(index), value) };
+            }
+            Ok(())
+        }
+
+        /// Adds `scalar` to every element of the memory block in place using saturating arithmetic.
+        /// 
+        /// Elements that would overflow clamp at `T::MAX` or `T::MIN` instead of wrapping around.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.saturating_add_scalar(100).unwrap();
+        /// ```
+        pub fn saturating_add_scalar(&mut self, scalar: T) -> Option<()>
+        where T: super::integer::Integer
+        {
+            if !self.check_ptr() {
+                return None;
+            }
+            for i in 0..self.memory_length {
+                unsafe {
+                    *self.ptr.add(i) = (*self.ptr.add(i)).saturating_add(scalar);
+                }
+            }
+            Some(())
+        }
+
+        /// Reorders the memory block so every element satisfying `pred` comes first, preserving relative order.
+        /// 
+        /// Returns the number of elements satisfying `pred`, which is the zero-based index of the first element 
+        /// of the second group (the partition point). Each matching element is rotated down to the partition 
+        /// point, so nothing is allocated, at the cost of `O(n * k)` element moves for `k` matching elements.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let point = mut_ptr.partition_in_place(|x| x % 2 == 0).unwrap();
+        /// ```
+        pub fn partition_in_place<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Option<usize> {
+            let slice: &mut [T] = self.as_mut_slice()?;
+            let mut point: usize = 0;
+            for i in 0..slice.len() {
+                if pred(&slice[i]) {
+                    slice[point..=i].rotate_right(1);
+                    point += 1;
+                }
+            }
+            Some(point)
+        }
+
+        /// Atomically adds `delta` to the element at `index` and returns the previous value.
+        /// 
+        /// This takes `&self` because atomic operations permit shared mutation, e.g. several threads counting 
+        /// into different slots of one memory block.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The value before the addition.
+        /// - `None`: If the pointer is invalid, `index >= memory_length`, or the element is not aligned for 
+        ///   the atomic type.
+        /// 
+        /// # Safety
+        /// 
+        /// Every concurrent access to the element must be atomic. While another thread may be calling this 
+        /// method, the element must not be read or written through non-atomic methods such as `read_at`, 
+        /// `access`, `as_slice` or `iter`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let previous = unsafe { mut_ptr.atomic_fetch_add_at(3, 1, std::sync::atomic::Ordering::Relaxed) }.unwrap();
+        /// ```
+        pub unsafe fn atomic_fetch_add_at(&self, index: usize, delta: T, ordering: std::sync::atomic::Ordering) -> Option<T>
+        where T: super::integer::AtomicInteger
+        {
+            if !self.check_ptr() || index >= self.memory_length {
+                return None;
+            }
+            T::atomic_fetch_add(self.ptr.add(index), delta, ordering)
+        }
+
+        /// Splits the memory block into `n` independently owned memory blocks of roughly equal length.
+        /// 
+        /// Each shard gets `memory_length / n` elements and the last shard also takes the remainder. The 
+        /// elements are copied into new allocations, so every shard can be sent to another thread and dropped 
+        /// on its own. The original memory block is released when `self` is dropped.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(Vec<MutRawPtr<T>>)`: The shards in order.
+        /// - `None`: If the pointer is invalid, `n` is 0, or `n > memory_length`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let shards: Vec<MutRawPtr<i32>> = mut_ptr.into_shards(3).unwrap();
+        /// ```
+        pub fn into_shards(self, n: usize) -> Option<Vec<MutRawPtr<T>>> {
+            if !self.check_ptr() || n == 0 || n > self.memory_length {
+                return None;
+            }
+            let shard_length: usize = self.memory_length / n;
+            let mut shards: Vec<MutRawPtr<T>> = Vec::with_capacity(n);
+            for i in 0..n {
+                let start: usize = i * shard_length;
+                let len: usize = if i == n - 1 { self.memory_length - start } else { shard_length };
+                let data: &[T] = unsafe { std::slice::from_raw_parts(self.ptr.add(start), len) };
+                shards.push(Self::c_malloc_from_slice(data)?);
+            }
+            Some(shards)
+        }
+
+        /// Performs a k-way merge of pre-sorted memory blocks into a single newly allocated memory block.
+        /// 
+        /// Each shard must already be sorted in non-decreasing order. Equal elements keep the order of the 
+        /// shards they came from. The shards are dropped once merged.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(MutRawPtr<T>)`: The merged, sorted memory block.
+        /// - `None`: If any shard is null or misaligned, or there are no elements to merge.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let merged: MutRawPtr<i32> = MutRawPtr::merge_sorted(vec![a, b, c]).unwrap();
+        /// ```
+        pub fn merge_sorted(shards: Vec<super::const_raw_ptr::ConstRawPtr<T>>) -> Option<MutRawPtr<T>>
+        where T: Ord
+        {
+            if shards.iter().any(|shard| !shard.check_ptr()) {
+                return None;
+            }
+            let total: usize = shards.iter().map(|shard| shard.memory_length).sum();
+            let mut heap: std::collections::BinaryHeap<std::cmp::Reverse<(T, usize, usize)>> = shards.iter()
+                .enumerate()
+                .filter(|(_, shard)| shard.memory_length > 0)
+                .map(|(s, shard)| std::cmp::Reverse((unsafe { *shard.ptr }, s, 0)))
+                .collect();
+            let mut merged: Vec<T> = Vec::with_capacity(total);
+            while let Some(std::cmp::Reverse((value, s, i))) = heap.pop() {
+                merged.push(value);
+                let shard: &super::const_raw_ptr::ConstRawPtr<T> = &shards[s];
+                if i + 1 < shard.memory_length {
+                    heap.push(std::cmp::Reverse((unsafe { *shard.ptr.add(i + 1) }, s, i + 1)));
+                }
+            }
+            Self::c_malloc_from_slice(&merged)
+        }
+
+        /// Calls `f` with a mutable reference to the element at the current offset, returning its result.
+        /// 
+        /// The reference only lives for the duration of the closure, so it cannot escape and alias other 
+        /// references to the same memory. Prefer this over `ref_mut`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.with_mut(|value| *value += 1).unwrap();
+        /// ```
+        /// 
+        /// The reference cannot be smuggled out of the closure:
+        /// 
+        /// ```compile_fail
+        /// use box_raw_ptr::mut_raw_ptr::MutRawPtr;
+        /// 
+        /// let mut arr: [i32; 1] = [0];
+        /// let mut view = MutRawPtr::from_array_mut(&mut arr);
+        /// let escaped: &mut i32 = view.with_mut(|value| value).unwrap();
+        /// ```
+        pub fn with_mut<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> Option<R> {
+            if !self.check_ptr() || !self.check_bounds() {
+                return None;
+            }
+            Some(f(unsafe { &mut *self.ptr.add(self.offset) }))
+        }
+
+        /// Shifts every element of the memory block by `by` positions, filling vacated slots with `fill`.
+        /// 
+        /// A positive `by` shifts toward the end and a negative `by` toward the start. Elements shifted past 
+        /// either end of the memory block are discarded. The offset is not changed.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.shift(2, 0).unwrap();
+        /// ```
+        pub fn shift(&mut self, by: isize, fill: T) -> Option<()> {
+            if !self.check_ptr() {
+                return None;
+            }
+            let len: usize = self.memory_length;
+            let distance: usize = by.unsigned_abs().min(len);
+            unsafe {
+                if by >= 0 {
+                    std::ptr::copy(self.ptr, self.ptr.add(distance), len - distance);
+                    (0..distance).for_each(|i| std::ptr::write(self.ptr.add(i), fill));
+                } else {
+                    std::ptr::copy(self.ptr.add(distance), self.ptr, len - distance);
+                    (len - distance..len).for_each(|i| std::ptr::write(self.ptr.add(i), fill));
+                }
+            }
+            Some(())
+        }
+
+        /// Writes `new` to the element at the current offset only if it currently equals `expected`.
+        /// 
+        /// This is a non-atomic compare-and-set intended for optimistic single-threaded updates.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(true)`: The element matched `expected` and was replaced with `new`.
+        /// - `Some(false)`: The element did not match and was left unchanged.
+        /// - `None`: If the pointer is invalid or the offset is out of bounds.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let swapped = mut_ptr.write_if_eq(0, 42).unwrap();
+        /// ```
+        pub fn write_if_eq(&mut self, expected: T, new: T) -> Option<bool>
+        where T: PartialEq
+        {
+            self.with_mut(|value| {
+                if *value == expected {
+                    *value = new;
+                    true
+                } else {
+                    false
+                }
+            })
+        }
+
+        /// Returns the `Layout` describing the full allocation behind the pointer.
+        /// 
+        /// The layout covers `capacity` elements of `T` (which equals the memory length unless the block was 
+        /// shrunk in place) with the alignment it was allocated with, matching what `c_malloc` or 
+        /// `c_malloc_aligned` allocated.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let layout = ptr.layout().unwrap();
+        /// ```
+        pub fn layout(&self) -> Option<std::alloc::Layout> {
+            if self.ptr.is_null() {
+                return None;
+            }
+            std::alloc::Layout::from_size_align(std::mem::size_of::<T>().checked_mul(self.capacity)?, self.align).ok()
+        }
+
+        /// Views the whole memory block as a slice.
+        /// 
+        /// The slice starts at the base of the allocation, not at the current offset, and spans `memory_length` elements.
+        /// The caller must guarantee every element in `0..memory_length` was initialized, as required by 
+        /// `std::slice::from_raw_parts`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(&[T])`: The memory block as a slice.
+        /// - `None`: If the pointer is invalid.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let total: i32 = ptr.as_slice().unwrap().iter().sum();
+        /// ```
+        pub fn as_slice(&self) -> Option<&[T]> {
+            if !self.check_ptr() {
+                return None;
+            }
+            Some(unsafe { std::slice::from_raw_parts(self.ptr, self.memory_length) })
+        }
+
+        /// Views the whole memory block as a mutable slice.
+        /// 
+        /// The slice starts at the base of the allocation, not at the current offset, and spans `memory_length` elements.
+        /// The caller must guarantee every element in `0..memory_length` was initialized, as required by 
+        /// `std::slice::from_raw_parts_mut`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(&mut [T])`: The memory block as a mutable slice.
+        /// - `None`: If the pointer is invalid.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// ptr.as_mut_slice().unwrap().sort();
+        /// ```
+        pub fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+            if !self.check_ptr() {
+                return None;
+            }
+            Some(unsafe { std::slice::from_raw_parts_mut(self.ptr, self.memory_length) })
+        }
+
+        /// Corrects the memory length of a pointer adopted with the wrong length.
+        /// 
+        /// Unlike `change_memory_length`, the new length is not limited by the capacity, and nothing is clamped. 
+        /// The capacity is corrected along with the memory length, since both were taken from the wrong length 
+        /// when the pointer was adopted.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the memory length was corrected.
+        /// - `None`: If the pointer is invalid, the current offset would fall outside `actual_length`, or 
+        ///   `actual_length` elements of `T` overflow `isize`.
+        /// 
+        /// # Safety
+        /// 
+        /// `actual_length` must be the true number of elements of the allocation. Every method bounded by the 
+        /// memory length reads or writes up to it, and an owned pointer deallocates a layout computed from it.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(unsafe { ptr.with_corrected_length(8) }.is_some());
+        /// ```
+        pub unsafe fn with_corrected_length(&mut self, actual_length: usize) -> Option<()> {
+            if !self.check_ptr() || self.offset >= actual_length || std::alloc::Layout::array::<T>(actual_length).is_err() {
+                return None;
+            }
+            self.memory_length = actual_length;
+            self.capacity = actual_length;
+            Some(())
+        }
+
+        /// Reinterprets the memory block as raw bytes, consuming the pointer.
+        /// 
+        /// The returned `MutRawPtr<u8>` covers the same allocation with the memory length and capacity scaled to 
+        /// bytes, and its offset points at the first byte of the current element. Ownership of the memory block 
+        /// moves to the byte pointer, so the allocation is still deallocated exactly once.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let bytes: MutRawPtr<u8> = mut_ptr.into_byte_ptr();
+        /// ```
+        pub fn into_byte_ptr(self) -> MutRawPtr<u8> {
+            let size: usize = std::mem::size_of::<T>();
+            let bytes: MutRawPtr<u8> = MutRawPtr {
+                ptr: self.ptr as *mut u8,
+                memory_length: self.memory_length * size,
+                capacity: self.capacity * size,
+                offset: self.offset * size,
+                owned: self.owned,
+                align: self.align,
+            };
+            std::mem::forget(self);
+            bytes
+        }
+
+        /// Reinterprets a byte pointer as a memory block of `T`, consuming the byte pointer.
+        /// 
+        /// This reverses `into_byte_ptr`. The memory length and capacity are scaled down to elements of `T`, and 
+        /// the offset points at the element containing the byte offset. Ownership of the memory block moves to the
+        /// returned pointer.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(MutRawPtr<T>)`: The memory block as elements of `T`.
+        /// - `None`: If the byte pointer is invalid, is not aligned for `T`, or its memory length is not a 
+        ///   multiple of `size_of::<T>()`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let mut_ptr: MutRawPtr<i32> = MutRawPtr::from_byte_ptr(bytes).unwrap();
+        /// ```
+        pub fn from_byte_ptr(bytes: MutRawPtr<u8>) -> Option<Self> {
+            let size: usize = std::mem::size_of::<T>();
+            if !bytes.check_ptr() || size == 0 || !(bytes.ptr as usize).is_multiple_of(std::mem::align_of::<T>()) {
+                return None;
+            }
+            if !bytes.memory_length.is_multiple_of(size) {
+                return None;
+            }
+            let ptr: MutRawPtr<T> = MutRawPtr {
+                ptr: bytes.ptr as *mut T,
+                memory_length: bytes.memory_length / size,
+                capacity: bytes.capacity / size,
+                offset: bytes.offset / size,
+                owned: bytes.owned,
+                align: bytes.align,
+            };
+            std::mem::forget(bytes);
+            Some(ptr)
+        }
+
+        /// Copies the whole memory block into a new `Vec<T>`.
+        /// 
+        /// The copy starts at the base of the allocation, not at the current offset, and spans `memory_length` 
+        /// elements. The pointer keeps ownership of the memory block and can still be used afterwards.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(Vec<T>)`: A copy of the memory block.
+        /// - `None`: If the pointer is invalid.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let copy: Vec<i32> = ptr.to_vec().unwrap();
+        /// ```
+        pub fn to_vec(&self) -> Option<Vec<T>> {
+            if !self.check_ptr() {
+                return None;
+            }
+            let mut vec: Vec<T> = Vec::with_capacity(self.memory_length);
+            unsafe {
+                std::ptr::copy_nonoverlapping(self.ptr, vec.as_mut_ptr(), self.memory_length);
+                vec.set_len(self.memory_length);
+            }
+            Some(vec)
+        }
+
+        /// Writes each value into the element at the matching index.
+        /// 
+        /// `values[i]` is written to element `indices[i]`, relative to the base of the allocation. Every index is 
+        /// checked before anything is written, so a rejected call leaves the memory block unchanged.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If every value was written.
+        /// - `None`: If the pointer is invalid, `indices` and `values` differ in length, or any index is 
+        ///   `>= memory_length`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.scatter(&[3, 0], &[7, 9]).unwrap();
+        /// ```
+        pub fn scatter(&mut self, indices: &[usize], values: &[T]) -> Option<()> {
+            if !self.check_ptr() || indices.len() != values.len() || indices.iter().any(|&i| i >= self.memory_length) {
+                return None;
+            }
+            for (&i, &value) in indices.iter().zip(values) {
+                unsafe { *self.ptr.add(i) = value };
+            }
+            Some(())
+        }
+
+        /// Checks if the allocation's byte range overlaps the byte range of a slice.
+        /// 
+        /// This is useful for choosing between `std::ptr::copy_nonoverlapping` and `std::ptr::copy` when one 
+        /// side of a copy is an external slice. A null pointer or an empty slice never aliases.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(!mut_ptr.aliases_slice(&[1, 2, 3]));
+        /// ```
+        pub fn aliases_slice(&self, s: &[T]) -> bool {
+            let size: usize = std::mem::size_of::<T>();
+            if self.ptr.is_null() || self.capacity == 0 || s.is_empty() || size == 0 {
+                return false;
+            }
+            let start: usize = self.ptr as usize;
+            let end: usize = start + size * self.capacity;
+            let s_start: usize = s.as_ptr() as usize;
+            let s_end: usize = s_start + std::mem::size_of_val(s);
+            start < s_end && s_start < end
+        }
+
+        /// Copies the elements of a slice into the memory block, starting at the current offset.
+        /// 
+        /// With the offset at the first element this is the inverse of `to_vec`. The bounds are checked before 
+        /// anything is written, so a rejected call leaves the memory block unchanged and can never write past the 
+        /// end of the allocation. A slice overlapping the allocation (for example one built from the same memory 
+        /// block) is rejected rather than copied with a `std::ptr::copy` fallback, since writing to memory behind 
+        /// a live `&[T]` is undefined behavior however the copy is done; use `copy_within` to move elements inside 
+        /// the block.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the slice was copied.
+        /// - `None`: If the pointer is invalid, the slice overlaps the allocation, or the slice doesn't fit between 
+        ///   the current offset and the end of the memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.copy_from_slice(&[1, 2, 3]).unwrap();
+        /// ```
+        pub fn copy_from_slice(&mut self, src: &[T]) -> Option<()> {
+            if !self.check_ptr() || !self.check_bounds() || self.offset.checked_add(src.len())? > self.memory_length {
+                return None;
+            }
+            if self.aliases_slice(src) {
+                return None;
+            }
+            unsafe { std::ptr::copy_nonoverlapping(src.as_ptr(), self.ptr.add(self.offset), src.len()) };
+            Some(())
+        }
+
+        /// Sets whether the pointer owns its memory block.
+        /// 
+        /// An owning pointer deallocates the memory block with this library's allocator when it is dropped. Only 
+        /// opt in for memory allocated by that allocator (e.g. with `std::alloc::alloc`); memory owned by C, 
+        /// the stack, or another Rust value must stay non-owned to avoid a double free.
+        /// 
+        /// # Safety
+        /// 
+        /// If `owned` is `true`, the memory block must have been allocated by this library's allocator with the 
+        /// layout of `capacity` elements of `T`, and nothing else may free it, including another owning pointer.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let mut ptr = MutRawPtr::new(alloc_ptr, 1, 0);
+        /// unsafe { ptr.set_owned(true) };
+        /// ```
+        #[inline]
+        pub unsafe fn set_owned(&mut self, owned: bool) {
+            self.owned = owned;
+        }
+
+        /// Reads the element at `index`, relative to the base of the allocation, without moving the offset.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element at `index`.
+        /// - `None`: If the pointer is invalid or `index >= memory_length`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let third: i32 = ptr.read_at(2).unwrap();
+        /// ```
+        pub fn read_at(&self, index: usize) -> Option<T> {
+            self.try_read_at(index).ok()
+        }
+
+        /// Writes a value into the element at `index`, relative to the base of the allocation, without moving 
+        /// the offset.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the value was written.
+        /// - `None`: If the pointer is invalid or `index >= memory_length`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.write_at(2, 14).unwrap();
+        /// ```
+        pub fn write_at(&mut self, index: usize, value: T) -> Option<()> {
+            if !self.check_ptr() || index >= self.memory_length {
+                return None;
+            }
+            unsafe { std::ptr::write(self.ptr.add(index), value) };
+            Some(())
+        }
+
+        /// Consumes the pointer and returns an iterator that yields every element by value, then frees the memory block.
+        /// 
+        /// The memory block is deallocated as soon as the last element has been yielded, or when the iterator is 
+        /// dropped early, whichever happens first. It is deallocated exactly once, and only if the pointer owned it. 
+        /// An invalid pointer yields nothing.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let first_two: Vec<i32> = mut_ptr.into_draining_iter().take(2).collect();
+        /// ```
+        pub fn into_draining_iter(self) -> impl Iterator<Item = T> {
+            let block: Option<MutRawPtr<T>> = if self.check_ptr() { Some(self) } else { None };
+            DrainingIter { block, index: 0 }
+        }
+
+        /// Writes `value` into every element of the memory block.
+        /// 
+        /// The whole block is filled starting at the base of the allocation, and the offset is left unchanged.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the memory block was filled.
+        /// - `None`: If the pointer is invalid.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.fill(0).unwrap();
+        /// ```
+        pub fn fill(&mut self, value: T) -> Option<()> {
+            if !self.check_ptr() {
+                return None;
+            }
+            for i in 0..self.memory_length {
+                unsafe { std::ptr::write(self.ptr.add(i), value) };
+            }
+            Some(())
+        }
+
+        /// Writes `f(i)` into every index `i` of the memory block.
+        /// 
+        /// The whole block is filled starting at the base of the allocation, and the offset is left unchanged.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the memory block was filled.
+        /// - `None`: If the pointer is invalid. `f` is never called.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.fill_with(|i| i as i32 * 2).unwrap();
+        /// ```
+        pub fn fill_with<F: FnMut(usize) -> T>(&mut self, mut f: F) -> Option<()> {
+            self.try_fill_with(|index| Ok::<T, std::convert::Infallible>(f(index))).ok()
+        }
+
+        /// Performs a volatile read of the element at the current offset.
+        /// 
+        /// Volatile reads are never elided or reordered with other volatile accesses by the compiler, which makes 
+        /// this suitable for memory-mapped I/O such as hardware registers.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element at the current offset.
+        /// - `None`: If the pointer is invalid or the offset is out of bounds.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let status: u32 = register.read_volatile().unwrap();
+        /// ```
+        pub fn read_volatile(&self) -> Option<T> {
+            if !self.check_ptr() || !self.check_bounds() {
+                return None;
+            }
+            Some( unsafe { std::ptr::read_volatile(self.ptr.add(self.offset)) } )
+        }
+
+        /// Performs a volatile write of `value` into the element at the current offset.
+        /// 
+        /// Volatile writes are never elided or reordered with other volatile accesses by the compiler, which makes 
+        /// this suitable for memory-mapped I/O such as hardware registers.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the value was written.
+        /// - `None`: If the pointer is invalid or the offset is out of bounds.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// register.write_volatile(0x1).unwrap();
+        /// ```
+        pub fn write_volatile(&mut self, value: T) -> Option<()> {
+            if !self.check_ptr() || !self.check_bounds() {
+                return None;
+            }
+            unsafe { std::ptr::write_volatile(self.ptr.add(self.offset), value) };
+            Some(())
+        }
+
+        /// Reads the element at the current offset without requiring the pointer to be aligned.
+        /// 
+        /// Uses `std::ptr::read_unaligned`, so it works for pointers created with `new_unaligned`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element at the current offset.
+        /// - `None`: If the pointer is null or the offset is out of bounds.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let value: u32 = ptr.access_unaligned().unwrap();
+        /// ```
+        pub fn access_unaligned(&self) -> Option<T> {
+            if self.ptr.is_null() || !self.check_bounds() {
+                return None;
+            }
+            Some( unsafe { std::ptr::read_unaligned(self.ptr.add(self.offset)) } )
+        }
+
+        /// Writes `value` into the element at the current offset without requiring the pointer to be aligned.
+        /// 
+        /// Uses `std::ptr::write_unaligned`, so it works for pointers created with `new_unaligned`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the value was written.
+        /// - `None`: If the pointer is null or the offset is out of bounds.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// ptr.write_unaligned(14).unwrap();
+        /// ```
+        pub fn write_unaligned(&mut self, value: T) -> Option<()> {
+            if self.ptr.is_null() || !self.check_bounds() {
+                return None;
+            }
+            unsafe { std::ptr::write_unaligned(self.ptr.add(self.offset), value) };
+            Some(())
+        }
+
+        /// Checks the pointer like `check_ptr`, reporting why it is invalid.
+        fn validate_ptr(&self) -> Result<(), super::error::BoxRawPtrError> {
+            if self.ptr.is_null() {
+                return Err(super::error::BoxRawPtrError::Null);
+            }
+            if !(self.ptr as usize).is_multiple_of(std::mem::align_of::<T>()) {
+                return Err(super::error::BoxRawPtrError::Misaligned);
+            }
+            Ok(())
+        }
+
+        /// Reads the element at the current offset, if valid.
+        /// 
+        /// See `try_access` for the reason of a failure.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let value: i32 = ptr.access().unwrap();
+        /// ```
+        pub fn access(&self) -> Option<T> {
+            self.try_access().ok()
+        }
+
+        /// Reads the element at the current offset.
+        /// 
+        /// # Errors
+        /// 
+        /// - `BoxRawPtrError::Null` or `BoxRawPtrError::Misaligned`: If the pointer is invalid.
+        /// - `BoxRawPtrError::OutOfBounds`: If the offset is outside the memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let value: i32 = ptr.try_access()?;
+        /// ```
+        pub fn try_access(&self) -> Result<T, super::error::BoxRawPtrError> {
+            self.validate_ptr()?;
+            if !self.check_bounds() {
+                return Err(super::error::BoxRawPtrError::OutOfBounds);
+            }
+            Ok( unsafe { *self.ptr.add(self.offset) } )
+        }
+
+        /// Changes the offset by a given index, if the resulting offset is within bounds.
+        /// 
+        /// # Errors
+        /// 
+        /// - `BoxRawPtrError::Null` or `BoxRawPtrError::Misaligned`: If the pointer is invalid.
+        /// - `BoxRawPtrError::OutOfBounds`: If the resulting offset is outside the memory block or the addition 
+        ///   overflows. The offset is left unchanged.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// ptr.try_change_offset(2)?;
+        /// ```
+        pub fn try_change_offset(&mut self, index: isize) -> Result<(), super::error::BoxRawPtrError> {
+            self.validate_ptr()?;
+            match self.offset.checked_add_signed(index) {
+                Some(new_offset) if new_offset < self.memory_length => {
+                    self.offset = new_offset;
+                    Ok(())
+                }
+                _ => Err(super::error::BoxRawPtrError::OutOfBounds),
+            }
+        }
+
+        /// Reads the element at `index`, relative to the base of the allocation, without moving the offset.
+        /// 
+        /// # Errors
+        /// 
+        /// - `BoxRawPtrError::Null` or `BoxRawPtrError::Misaligned`: If the pointer is invalid.
+        /// - `BoxRawPtrError::OutOfBounds`: If `index >= memory_length`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let third: i32 = ptr.try_read_at(2)?;
+        /// ```
+        pub fn try_read_at(&self, index: usize) -> Result<T, super::error::BoxRawPtrError> {
+            self.validate_ptr()?;
+            if index >= self.memory_length {
+                return Err(super::error::BoxRawPtrError::OutOfBounds);
+            }
+            Ok( unsafe { *self.ptr.add(index) } )
+        }
+
+        /// Writes a value into the element at the current offset.
+        /// 
+        /// # Errors
+        /// 
+        /// - `BoxRawPtrError::Null` or `BoxRawPtrError::Misaligned`: If the pointer is invalid.
+        /// - `BoxRawPtrError::OutOfBounds`: If the offset is outside the memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.try_write_ptr(42)?;
+        /// ```
+        pub fn try_write_ptr(&mut self, src: T) -> Result<(), super::error::BoxRawPtrError> {
+            self.validate_ptr()?;
+            if !self.check_bounds() {
+                return Err(super::error::BoxRawPtrError::OutOfBounds);
+            }
+            unsafe { std::ptr::write(self.ptr.add(self.offset), src) };
+            Ok(())
+        }
+
+        /// Moves the offset to the absolute element `index`, counted from the base of the allocation.
+        /// 
+        /// Unlike `change_offset`, the new position does not depend on the current offset. The base pointer 
+        /// itself never moves, so repeated jumps can't drift away from the allocation.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the offset was moved.
+        /// - `None`: If the pointer is invalid or `index >= memory_length`. The offset is left unchanged.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// ptr.set_offset(3).unwrap();
+        /// ```
+        pub fn set_offset(&mut self, index: usize) -> Option<()> {
+            if !self.check_ptr() || index >= self.memory_length {
+                return None;
+            }
+            self.offset = index;
+            Some(())
+        }
+
+        /// Moves the offset back to the first element of the memory block.
+        /// 
+        /// The offset is stored separately from the base pointer, which never moves, so rewinding only resets 
+        /// the offset to 0 and the pointer keeps its original provenance.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// ptr.reset_offset();
+        /// ```
+        #[inline]
+        pub fn reset_offset(&mut self) {
+            self.offset = 0;
+        }
+
+        /// Resizes the owned memory block to `new_length` elements with `std::alloc::realloc`.
+        /// 
+        /// The elements in the overlapping prefix are preserved and the offset is clamped to the new memory length. 
+        /// Only memory blocks owned by the pointer (e.g. from `c_malloc`) can be resized. On failure the original 
+        /// memory block, memory length, and offset are left intact.
+        /// 
+        /// # Safety
+        /// 
+        /// When growing, the new elements are not initialized. The caller must write them before reading them.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the memory block was resized.
+        /// - `None`: If the pointer is invalid or not owned, `new_length` is 0, or the reallocation failed.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// unsafe { ptr.c_realloc(16).unwrap() };
+        /// ```
+        pub unsafe fn c_realloc(&mut self, new_length: usize) -> Option<()> {
+            if !self.owned || !self.check_ptr() || new_length == 0 {
+                return None;
+            }
+            let layout: std::alloc::Layout = self.layout()?;
+            let new_size: usize = std::alloc::Layout::array::<T>(new_length).ok()?.size();
+
+            let ptr: *mut T = std::alloc::realloc(self.ptr as *mut u8, layout, new_size) as *mut T;
+            if ptr.is_null() {
+                return None;
+            }
+            #[cfg(feature = "debug-trace")]
+            {
+                super::debug_trace::forget(self.ptr as usize);
+                super::debug_trace::record(ptr as usize, new_length);
+            }
+
+            self.ptr = ptr;
+            self.memory_length = new_length;
+            self.capacity = new_length;
+            self.offset = self.offset.min(new_length - 1);
+            Some(())
+        }
+
+        /// Checks if both pointers point to the same element, by address.
+        /// 
+        /// Unlike `==`, this never dereferences either pointer.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(!mut_ptr.ptr_eq(&other));
+        /// ```
+        #[inline]
+        pub fn ptr_eq(&self, other: &Self) -> bool {
+            self.ptr.wrapping_add(self.offset) == other.ptr.wrapping_add(other.offset)
+        }
+
+        /// Swaps the values at the current offsets of two pointers.
+        /// 
+        /// Both pointers may point into the same memory block, including at the same element.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the values were swapped.
+        /// - `None`: If either pointer is invalid or its offset is out of bounds.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.swap(&mut other).unwrap();
+        /// ```
+        pub fn swap(&mut self, other: &mut MutRawPtr<T>) -> Option<()> {
+            if !self.check_ptr() || !self.check_bounds() || !other.check_ptr() || !other.check_bounds() {
+                return None;
+            }
+            unsafe { std::ptr::swap(self.ptr.add(self.offset), other.ptr.add(other.offset)) };
+            Some(())
+        }
+
+        /// Swaps the elements at indices `i` and `j`, relative to the base of the allocation.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the elements were swapped.
+        /// - `None`: If the pointer is invalid or either index is `>= memory_length`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.swap_at(0, 3).unwrap();
+        /// ```
+        pub fn swap_at(&mut self, i: usize, j: usize) -> Option<()> {
+            if !self.check_ptr() || i >= self.memory_length || j >= self.memory_length {
+                return None;
+            }
+            unsafe { std::ptr::swap(self.ptr.add(i), self.ptr.add(j)) };
+            Some(())
+        }
+
+        /// Sorts the whole memory block in place.
+        /// 
+        /// This sorts `as_mut_slice()` with the slice's stable `sort`, so every element in `0..memory_length` 
+        /// must be initialized.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.sort_block().unwrap();
+        /// ```
+        pub fn sort_block(&mut self) -> Option<()>
+        where T: Ord
+        {
+            self.as_mut_slice()?.sort();
+            Some(())
+        }
+
+        /// Sorts the whole memory block in place with a comparator function.
+        /// 
+        /// This sorts `as_mut_slice()` with the slice's stable `sort_by`, so every element in `0..memory_length` 
+        /// must be initialized.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.sort_block_by(|a, b| b.cmp(a)).unwrap();
+        /// ```
+        pub fn sort_block_by<F: FnMut(&T, &T) -> std::cmp::Ordering>(&mut self, f: F) -> Option<()> {
+            self.as_mut_slice()?.sort_by(f);
+            Some(())
+        }
+
+        /// Reverses the order of the elements in the whole memory block in place.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.reverse_block().unwrap();
+        /// ```
+        pub fn reverse_block(&mut self) -> Option<()> {
+            self.as_mut_slice()?.reverse();
+            Some(())
+        }
+
+        /// Creates a non-owning mutable view over a single mutably borrowed value.
+        /// 
+        /// The view has a memory length of 1 and an offset of 0, and never deallocates on drop, so it is safe to 
+        /// use with stack or otherwise borrowed memory. It mutably borrows `value`, so it can't outlive it and 
+        /// `value` can't be accessed directly while the view is alive.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let mut value: i32 = 5;
+        /// let mut ptr = MutRawPtr::from_mut(&mut value);
+        /// ```
+        /// 
+        /// The view cannot outlive the value:
+        /// 
+        /// ```compile_fail,E0597
+        /// use box_raw_ptr::mut_raw_ptr::MutRawPtr;
+        /// 
+        /// let view = {
+        ///     let mut value: i32 = 5;
+        ///     MutRawPtr::from_mut(&mut value)
+        /// };
+        /// ```
+        #[inline]
+        pub fn from_mut(value: &mut T) -> BorrowedMutRawPtr<'_, T> {
+            let ptr: *mut T = value;
+            BorrowedMutRawPtr::new(Self { ptr, memory_length: 1, capacity: 1, offset: 0, owned: false, align: std::mem::align_of::<T>() })
+        }
+
+        /// Returns a `NonNull<T>` pointing at the element at the current offset.
+        /// 
+        /// This is useful for composing with `NonNull`-oriented APIs. The `NonNull` does not carry the memory 
+        /// length or ownership of the pointer.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(NonNull<T>)`: A pointer to the current element.
+        /// - `None`: If the pointer is null.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let non_null: std::ptr::NonNull<i32> = ptr.as_non_null().unwrap();
+        /// ```
+        #[inline]
+        pub fn as_non_null(&self) -> Option<std::ptr::NonNull<T>> {
+            std::ptr::NonNull::new(self.ptr.wrapping_add(self.offset))
+        }
+
+        /// Decomposes the pointer into its base pointer, memory length, and offset without deallocating.
+        /// 
+        /// The memory block is not freed, so ownership passes to the caller, who can rebuild the pointer with 
+        /// `from_raw_parts`. The pair round-trips losslessly for pointers whose capacity equals their memory 
+        /// length and whose alignment is that of `T`, such as pointers from `c_malloc`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let (raw, memory_length, offset) = mut_ptr.into_raw_parts();
+        /// ```
+        pub fn into_raw_parts(self) -> (*mut T, usize, usize) {
+            let parts: (*mut T, usize, usize) = (self.ptr, self.memory_length, self.offset);
+            std::mem::forget(self);
+            parts
+        }
+
+        /// Rebuilds an owning pointer from the parts returned by `into_raw_parts`.
+        /// 
+        /// # Safety
+        /// 
+        /// `ptr` must point to an allocation of at least `memory_length` elements made by this library's 
+        /// allocator with the alignment of `T`, and nothing else may free it, since the returned pointer 
+        /// deallocates it on drop.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let ptr = unsafe { MutRawPtr::from_raw_parts(raw, memory_length, offset) };
+        /// ```
+        pub unsafe fn from_raw_parts(ptr: *mut T, memory_length: usize, offset: usize) -> Self {
+            Self { ptr, memory_length, capacity: memory_length, offset, owned: true, align: std::mem::align_of::<T>() }
+        }
+
+        /// Returns a reference to the element at `index`, relative to the base of the allocation.
+        /// 
+        /// The reference borrows the pointer, so it can't outlive it.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(&T)`: A reference to the element at `index`.
+        /// - `None`: If the pointer is invalid or `index >= memory_length`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let third: &i32 = ptr.get(2).unwrap();
+        /// ```
+        pub fn get(&self, index: usize) -> Option<&T> {
+            if !self.check_ptr() || index >= self.memory_length {
+                return None;
+            }
+            Some( unsafe { &*self.ptr.add(index) } )
+        }
+
+        /// Returns a mutable reference to the element at `index`, relative to the base of the allocation.
+        /// 
+        /// The reference mutably borrows the pointer, so it can't outlive it.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(&mut T)`: A mutable reference to the element at `index`.
+        /// - `None`: If the pointer is invalid or `index >= memory_length`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// *mut_ptr.get_mut(2).unwrap() += 1;
+        /// ```
+        pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+            if !self.check_ptr() || index >= self.memory_length {
+                return None;
+            }
+            Some( unsafe { &mut *self.ptr.add(index) } )
+        }
+
+        /// Copies `count` elements from the current offset of `src` to the current offset of `self`.
+        /// 
+        /// Nothing is written unless both memory blocks have `count` elements remaining from their offsets. The 
+        /// copy uses `std::ptr::copy` on the raw pointers, so `src` may be a view of the same memory block.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the elements were copied.
+        /// - `None`: If either pointer is invalid or either memory block has fewer than `count` elements 
+        ///   remaining from its offset.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.copy_from(&const_ptr, 4).unwrap();
+        /// ```
+        pub fn copy_from(&mut self, src: &super::const_raw_ptr::ConstRawPtr<T>, count: usize) -> Option<()> {
+            if !src.check_ptr() || !src.check_bounds() || src.offset.checked_add(count)? > src.memory_length {
+                return None;
+            }
+            if !self.check_ptr() || !self.check_bounds() || self.offset.checked_add(count)? > self.memory_length {
+                return None;
+            }
+            unsafe { std::ptr::copy(src.ptr.add(src.offset), self.ptr.add(self.offset), count) };
+            Some(())
+        }
+
+        /// Copies `count` elements from `src_index` to `dst_index` within the memory block.
+        /// 
+        /// Both indices are relative to the base of the allocation. The ranges may overlap, since the copy uses 
+        /// `std::ptr::copy`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the elements were copied.
+        /// - `None`: If the pointer is invalid or either range extends past the memory length.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// mut_ptr.copy_within(0, 2, 3).unwrap();
+        /// ```
+        pub fn copy_within(&mut self, src_index: usize, dst_index: usize, count: usize) -> Option<()> {
+            if !self.check_ptr() {
+                return None;
+            }
+            if src_index.checked_add(count)? > self.memory_length || dst_index.checked_add(count)? > self.memory_length {
+                return None;
+            }
+            unsafe { std::ptr::copy(self.ptr.add(src_index), self.ptr.add(dst_index), count) };
+            Some(())
+        }
+
+        /// Returns the number of elements from the current offset to the end of the memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// while ptr.remaining() > 0 { /* ... */ }
+        /// ```
+        pub fn remaining(&self) -> usize {
+            self.memory_length.saturating_sub(self.offset)
+        }
+
+        /// Reads the first element of the memory block without moving the offset.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element at the base of the allocation.
+        /// - `None`: If the pointer is invalid or the memory block is empty.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let head: i32 = ptr.first().unwrap();
+        /// ```
+        pub fn first(&self) -> Option<T> {
+            self.read_at(0)
+        }
+
+        /// Reads the last element of the memory block without moving the offset.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element at `memory_length - 1`.
+        /// - `None`: If the pointer is invalid or the memory block is empty.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let tail: i32 = ptr.last().unwrap();
+        /// ```
+        pub fn last(&self) -> Option<T> {
+            self.read_at(self.memory_length.checked_sub(1)?)
+        }
+
+        /// Returns an iterator over non-overlapping groups of up to `size` elements of the memory block.
+        /// 
+        /// The last group is shorter when `memory_length` is not a multiple of `size`. An invalid pointer yields 
+        /// no groups.
+        /// 
+        /// # Panics
+        /// 
+        /// Panics if `size` is 0.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// for chunk in ptr.chunks(4) { /* ... */ }
+        /// ```
+        pub fn chunks(&self, size: usize) -> std::slice::Chunks<'_, T> {
+            self.as_slice().unwrap_or(&[]).chunks(size)
+        }
+
+        /// Returns an iterator over overlapping groups of exactly `size` elements of the memory block.
+        /// 
+        /// No groups are yielded when `size > memory_length` or the pointer is invalid.
+        /// 
+        /// # Panics
+        /// 
+        /// Panics if `size` is 0.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let increasing: bool = ptr.windows(2).all(|w| w[0] <= w[1]);
+        /// ```
+        pub fn windows(&self, size: usize) -> std::slice::Windows<'_, T> {
+            self.as_slice().unwrap_or(&[]).windows(size)
+        }
+
+        /// Reads the element at the current offset without moving the cursor.
+        /// 
+        /// This is the same as `access`, named to pair with `advance`, `retreat` and `peek`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let byte: u8 = cursor.current().unwrap();
+        /// ```
+        pub fn current(&self) -> Option<T> {
+            self.access()
+        }
+
+        /// Reads the element at the current offset, then moves the cursor forward by one.
+        /// 
+        /// After the last element is read the offset sits one past the end, where `check_bounds` is false and 
+        /// further calls return `None` until the cursor is moved back with `retreat` or `set_offset`.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element the cursor was on.
+        /// - `None`: If the pointer is invalid or the cursor is at the end.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// while let Some(byte) = cursor.advance() { /* ... */ }
+        /// ```
+        pub fn advance(&mut self) -> Option<T> {
+            let value: T = self.access()?;
+            self.offset += 1;
+            Some(value)
+        }
+
+        /// Moves the cursor back by one, then reads the element at the new offset.
+        /// 
+        /// This undoes `advance`, returning the element it returned.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element the cursor moved onto.
+        /// - `None`: If the pointer is invalid or the cursor is at the base of the allocation.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let previous: u8 = cursor.retreat().unwrap();
+        /// ```
+        pub fn retreat(&mut self) -> Option<T> {
+            let value: T = self.read_at(self.offset.checked_sub(1)?)?;
+            self.offset -= 1;
+            Some(value)
+        }
+
+        /// Reads the element `ahead` elements past the current offset without moving the cursor.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(T)`: The element at `offset + ahead`.
+        /// - `None`: If the pointer is invalid or `offset + ahead >= memory_length`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let next: u8 = cursor.peek(1).unwrap();
+        /// ```
+        pub fn peek(&self, ahead: usize) -> Option<T> {
+            self.read_at(self.offset.checked_add(ahead)?)
+        }
+
+        /// Views the whole memory block as raw bytes, mutably.
+        /// 
+        /// The slice starts at the base of the allocation and spans `memory_length * size_of::<T>()` bytes, 
+        /// independent of the current offset.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(&mut [u8])`: The memory block as bytes.
+        /// - `None`: If the pointer is invalid or the byte length overflows `usize`.
+        /// 
+        /// # Safety
+        /// 
+        /// Every element in `0..memory_length` must be initialized and `T` must have no padding bytes. Any bytes 
+        /// written must leave every element a valid value of `T`, e.g. only `0` or `1` for `bool`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// unsafe { mut_ptr.as_bytes_mut() }.unwrap()[0] = 0xFF;
+        /// ```
+        pub unsafe fn as_bytes_mut(&mut self) -> Option<&mut [u8]> {
+            if !self.check_ptr() {
+                return None;
+            }
+            let bytes: usize = self.memory_length.checked_mul(std::mem::size_of::<T>())?;
+            Some(std::slice::from_raw_parts_mut(self.ptr as *mut u8, bytes))
+        }
+
+        /// Returns the index of the first element equal to `needle`.
+        /// 
+        /// The scan covers `0..memory_length` from the base of the allocation, independent of the current offset.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(usize)`: The absolute index of the first match.
+        /// - `None`: If the pointer is invalid or no element matches.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let newline: usize = bytes.position(b'\n').unwrap();
+        /// ```
+        pub fn position(&self, needle: T) -> Option<usize>
+        where T: PartialEq
+        {
+            self.as_slice()?.iter().position(|&value| value == needle)
+        }
+
+        /// Checks if any element of the memory block is equal to `needle`.
+        /// 
+        /// An invalid pointer contains nothing.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(bytes.contains(0));
+        /// ```
+        pub fn contains(&self, needle: T) -> bool
+        where T: PartialEq
+        {
+            self.position(needle).is_some()
+        }
+
+        /// Moves the offset by `count` elements, clamping it to the first or last element.
+        /// 
+        /// Nothing happens if the pointer is invalid or the memory block is empty.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// ptr.saturating_offset(isize::MAX);
+        /// assert_eq!(ptr.check_offset(), ptr.check_memory_length() - 1);
+        /// ```
+        pub fn saturating_offset(&mut self, count: isize) {
+            if !self.check_ptr() || self.memory_length == 0 {
+                return;
+            }
+            self.offset = self.offset.saturating_add_signed(count).min(self.memory_length - 1);
+        }
+
+        /// Moves the offset by `count` elements, wrapping around modulo the memory length.
+        /// 
+        /// Nothing happens if the pointer is invalid or the memory block is empty.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// ptr.wrapping_offset(-1);
+        /// assert_eq!(ptr.check_offset(), ptr.check_memory_length() - 1);
+        /// ```
+        pub fn wrapping_offset(&mut self, count: isize) {
+            if !self.check_ptr() || self.memory_length == 0 {
+                return;
+            }
+            let offset: i128 = (self.offset as i128 + count as i128).rem_euclid(self.memory_length as i128);
+            self.offset = offset as usize;
+        }
+
+        /// Atomically replaces the base pointer with `new` and returns the previous base pointer.
+        /// 
+        /// The swap goes through an `AtomicPtr` view of the pointer field with the given ordering. It takes 
+        /// `&mut self` rather than `&self` because the field is not an `UnsafeCell`, so writing it through a 
+        /// shared reference would be undefined behavior; share the wrapper behind a lock, or keep an 
+        /// `AtomicPtr` of your own and adopt the result, if other threads need to reseat it concurrently.
+        /// 
+        /// The memory length, capacity, offset and ownership are kept. If the pointer is owned, the allocation 
+        /// behind `new` is freed on drop instead of the previous one, and the previous allocation becomes the 
+        /// caller's responsibility.
+        /// 
+        /// # Safety
+        /// 
+        /// `new` must be null or point to at least `capacity` initialized elements of `T`. If the pointer is 
+        /// owned, `new` must have been allocated with the same layout, e.g. by `c_malloc` with the same capacity.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let old: *mut i32 = unsafe { mut_ptr.swap_ptr(replacement, std::sync::atomic::Ordering::AcqRel) };
+        /// ```
+        pub unsafe fn swap_ptr(&mut self, new: *mut T, order: std::sync::atomic::Ordering) -> *mut T {
+            let old: *mut T = std::sync::atomic::AtomicPtr::from_ptr(&mut self.ptr).swap(new, order);
+            #[cfg(feature = "debug-trace")]
+            if self.owned {
+                super::debug_trace::forget(old as usize);
+                super::debug_trace::record(new as usize, self.capacity);
+            }
+            old
+        }
+
+        /// Atomically replaces the base pointer with `new` if it is currently `current`.
+        /// 
+        /// This is `AtomicPtr::compare_exchange` on the pointer field, with the same `&mut self` requirement and 
+        /// ownership rules as `swap_ptr`. Nothing changes when the exchange fails.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Ok(*mut T)`: The previous base pointer, equal to `current`, if it was replaced.
+        /// - `Err(*mut T)`: The actual base pointer, if it was not equal to `current`.
+        /// 
+        /// # Safety
+        /// 
+        /// The same requirements on `new` as `swap_ptr`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// use std::sync::atomic::Ordering;
+        /// 
+        /// let reseated = unsafe { mut_ptr.compare_exchange_ptr(expected, replacement, Ordering::AcqRel, Ordering::Acquire) };
+        /// ```
+        pub unsafe fn compare_exchange_ptr(&mut self, current: *mut T, new: *mut T, success: std::sync::atomic::Ordering, failure: std::sync::atomic::Ordering) -> Result<*mut T, *mut T> {
+            let result: Result<*mut T, *mut T> = std::sync::atomic::AtomicPtr::from_ptr(&mut self.ptr).compare_exchange(current, new, success, failure);
+            #[cfg(feature = "debug-trace")]
+            if let (Ok(old), true) = (result, self.owned) {
+                super::debug_trace::forget(old as usize);
+                super::debug_trace::record(new as usize, self.capacity);
+            }
+            result
+        }
+    }
+
+    /// Clones the pointer as a non-owned view of the same memory block.
+    /// 
+    /// Only the original frees an owned allocation, so the clone must not be used after it is dropped.
+    impl<T: Sized + Copy + Send + Sync> Clone for MutRawPtr<T> {
+        fn clone(&self) -> Self {
+            Self { ptr: self.ptr, memory_length: self.memory_length, capacity: self.capacity, offset: self.offset, owned: false, align: self.align }
+        }
+    }
+
+    impl<T: Sized + Copy + Send + Sync + std::fmt::Debug> std::fmt::Debug for MutRawPtr<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let mut debug = f.debug_struct("MutRawPtr");
+            if self.ptr.is_null() {
+                debug.field("ptr", &format_args!("null"));
+            } else {
+                debug.field("ptr", &format_args!("{:#x}", self.ptr as usize));
+            }
+            debug.field("len", &self.memory_length).field("offset", &self.offset);
+            if let Some(value) = self.access() {
+                debug.field("value", &value);
+            }
+            debug.finish()
+        }
+    }
+
+    impl<T: Sized + Copy + Send + Sync> std::fmt::Pointer for MutRawPtr<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            std::fmt::Pointer::fmt(&self.ptr, f)
+        }
+    }
+
+    impl<T: Sized + Copy + Send + Sync> std::fmt::LowerHex for MutRawPtr<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            std::fmt::LowerHex::fmt(&(self.ptr as usize), f)
+        }
+    }
+
+    impl<T: Sized + Copy + Send + Sync> std::fmt::UpperHex for MutRawPtr<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            std::fmt::UpperHex::fmt(&(self.ptr as usize), f)
+        }
+    }
+
+    /// Compares the values at the current offsets of both pointers.
+    /// 
+    /// This dereferences both pointers, so both must be valid and point to initialized memory. Two null 
+    /// pointers are equal, and two otherwise invalid pointers are equal if `ptr_eq` holds, which keeps the 
+    /// comparison reflexive. An invalid pointer is never equal to a valid one. Use `ptr_eq` to compare 
+    /// addresses instead.
+    impl<T: Sized + Copy + Send + Sync + PartialEq> PartialEq for MutRawPtr<T> {
+        fn eq(&self, other: &Self) -> bool {
+            if self.ptr.is_null() && other.ptr.is_null() {
+                return true;
+            }
+            match (self.access(), other.access()) {
+                (Some(a), Some(b)) => a == b,
+                (None, None) => self.ptr_eq(other),
+                _ => false,
+            }
+        }
+    }
+
+    impl<T: Sized + Copy + Send + Sync + Eq> Eq for MutRawPtr<T> {}
+
+    /* 
+    Raw pointers are neither Send nor Sync, T: Send + Sync is required so
+    the pointed-to data may be shared, e.g. through atomic_fetch_add_at
+    Every method writing through &self must be atomic and unsafe, since
+    safe code could otherwise race it with a non-atomic read
+    */
+    unsafe impl<T: Sized + Copy + Send + Sync> Send for MutRawPtr<T> {}
+
+    unsafe impl<T: Sized + Copy + Send + Sync> Sync for MutRawPtr<T> {}
+
+    /// Indexes the memory block relative to the base of the allocation, like a slice.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics with `"box_raw_ptr Err: Index Out Of Bounds"` if the pointer is invalid or `index >= memory_length`.
+    /// Use `get` for a non-panicking alternative.
+    impl<T: Sized + Copy + Send + Sync> std::ops::Index<usize> for MutRawPtr<T> {
+        type Output = T;
+
+        fn index(&self, index: usize) -> &T {
+            self.get(index).expect("box_raw_ptr Err: Index Out Of Bounds")
+        }
+    }
+
+    /// Mutably indexes the memory block relative to the base of the allocation, like a slice.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics with `"box_raw_ptr Err: Index Out Of Bounds"` if the pointer is invalid or `index >= memory_length`.
+    /// Use `get_mut` for a non-panicking alternative.
+    impl<T: Sized + Copy + Send + Sync> std::ops::IndexMut<usize> for MutRawPtr<T> {
+        fn index_mut(&mut self, index: usize) -> &mut T {
+            self.get_mut(index).expect("box_raw_ptr Err: Index Out Of Bounds")
+        }
+    }
+
+    impl<T: Sized + Copy + Send + Sync> Drop for MutRawPtr<T> {
+        fn drop(&mut self) {
+            if self.owned && self.check_ptr() {
+                let size: usize = std::mem::size_of::<T>() * self.capacity;
+                let layout: std::alloc::Layout = std::alloc::Layout::from_size_align(size, self.align).expect("Invalid alignment or size parameters.");
+                unsafe {
+                    std::alloc::dealloc(self.ptr as *mut u8, layout);
+                }
+                #[cfg(feature = "debug-trace")]
+                super::debug_trace::forget(self.ptr as usize);
+            }
+            self.memory_length = 0;
+            self.offset = 0;
+            self.ptr = std::ptr::null_mut();
+        }
+    }
+
+    impl MutRawPtr<u8> {
+        /// Allocates a new memory block from a hexadecimal string.
+        /// 
+        /// Every pair of hex digits (upper or lowercase) becomes one byte of the block, in order. The block 
+        /// is allocated with `c_malloc` and its offset starts at the first byte.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(MutRawPtr<u8>)`: A pointer to the newly allocated bytes.
+        /// - `None`: If the string is empty, has an odd length, or contains non-hex characters.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let bytes = MutRawPtr::from_hex_string("deadbeef").unwrap();
+        /// ```
+        pub fn from_hex_string(s: &str) -> Option<Self> {
+            let digits: &[u8] = s.as_bytes();
+            if !digits.len().is_multiple_of(2) || !digits.iter().all(|d| d.is_ascii_hexdigit()) {
+                return None;
+            }
+            let memory_length: usize = digits.len() / 2;
+            let block: Self = unsafe { Self::c_malloc(memory_length, 0) }?;
+            for (i, pair) in digits.chunks(2).enumerate() {
+                let byte: u8 = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+                unsafe { std::ptr::write(block.ptr.add(i), byte) };
+            }
+            Some(block)
+        }
+
+        /// Returns a `std::io::Write` implementation over the bytes of the memory block.
+        /// 
+        /// Writes start at the first byte of the block and advance an internal position that is bounded by 
+        /// the memory length. Once the block is full, writes become short and `write_all` fails with 
+        /// `std::io::ErrorKind::WriteZero`.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// write!(mut_ptr.as_writer().unwrap(), "{}", 42).unwrap();
+        /// ```
+        pub fn as_writer(&mut self) -> Option<impl std::io::Write + '_> {
+            if !self.check_ptr() {
+                return None;
+            }
+            let bytes: &mut [u8] = unsafe { std::slice::from_raw_parts_mut(self.ptr, self.memory_length) };
+            Some(std::io::Cursor::new(bytes))
+        }
+
+        /// Allocates a new memory block of `len` bytes and fills it by reading exactly `len` bytes from `reader`.
+        /// 
+        /// This is the ingest counterpart to `as_writer`. If the read fails, the partially filled block is 
+        /// released before returning.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(MutRawPtr<u8>)`: The newly allocated block holding the bytes read.
+        /// - `None`: If `len` is 0, the allocation failed, or `reader` could not provide `len` bytes.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let bytes = MutRawPtr::from_reader(&mut file, 512).unwrap();
+        /// ```
+        pub fn from_reader<R: std::io::Read>(reader: &mut R, len: usize) -> Option<Self> {
+            let block: Self = unsafe { Self::c_malloc(len, 0) }?;
+            unsafe { std::ptr::write_bytes(block.ptr, 0, len) };
+            let bytes: &mut [u8] = unsafe { std::slice::from_raw_parts_mut(block.ptr, len) };
+            reader.read_exact(bytes).ok()?;
+            Some(block)
+        }
+    }
+
+    /// A non-owning `MutRawPtr` tied to the lifetime of the memory it borrows.
+    /// 
+    /// `BorrowedMutRawPtr` is returned by `MutRawPtr::from_array_mut`, `MutRawPtr::from_mut`, 
+    /// `MutRawPtr::cast_ptr` and `MutRawPtr::project`. It only forwards the `MutRawPtr` methods that can't hand out a handle outliving the 
+    /// borrow or reallocate it, so it doesn't dereference to the wrapped `MutRawPtr`. Dropping it never 
+    /// deallocates.
+    pub struct BorrowedMutRawPtr<'a, T> 
+    where  T: Sized + Copy + Send + Sync
+    {
+        inner: MutRawPtr<T>,
+        _borrow: std::marker::PhantomData<&'a mut [T]>,
+    }
+
+    impl<'a, T: Sized + Copy + Send + Sync> BorrowedMutRawPtr<'a, T> {
+        /* Wraps a non-owned pointer into memory mutably borrowed for 'a */
+        fn new(inner: MutRawPtr<T>) -> Self {
+            Self { inner, _borrow: std::marker::PhantomData }
+        }
+
+        /// Returns the current offset. See `MutRawPtr::check_offset`.
+        pub fn check_offset(&self) -> usize {
+            self.inner.check_offset()
+        }
+
+        /// Returns the memory length, which is the length of the borrowed array. See `MutRawPtr::check_memory_length`.
+        pub fn check_memory_length(&self) -> usize {
+            self.inner.check_memory_length()
+        }
+
+        /// Changes the offset by a given index, if the resulting offset is within bounds. See `MutRawPtr::change_offset`.
+        pub fn change_offset(&mut self, index: isize) -> Option<()> {
+            self.inner.change_offset(index)
+        }
+
+        /// Reads the element at the current offset. See `MutRawPtr::access`.
+        pub fn access(&self) -> Option<T> {
+            self.inner.access()
+        }
+
+        /// Writes `src` at the current offset. See `MutRawPtr::write_ptr`.
+        pub fn write_ptr(&mut self, src: T) -> Option<()> {
+            self.inner.write_ptr(src)
+        }
+
+        /// Reads the element at `index` without moving the offset. See `MutRawPtr::read_at`.
+        pub fn read_at(&self, index: usize) -> Option<T> {
+            self.inner.read_at(index)
+        }
+
+        /// Writes `value` at `index` without moving the offset. See `MutRawPtr::write_at`.
+        pub fn write_at(&mut self, index: usize, value: T) -> Option<()> {
+            self.inner.write_at(index, value)
+        }
+
+        /// Views the borrowed array as a slice. See `MutRawPtr::as_slice`.
+        pub fn as_slice(&self) -> Option<&[T]> {
+            self.inner.as_slice()
+        }
+
+        /// Views the borrowed array as a mutable slice. See `MutRawPtr::as_mut_slice`.
+        pub fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+            self.inner.as_mut_slice()
+        }
+
+        /// Writes `value` into every element. See `MutRawPtr::fill`.
+        pub fn fill(&mut self, value: T) -> Option<()> {
+            self.inner.fill(value)
+        }
+
+        /// Calls `f` with a mutable reference to the element at the current offset. See `MutRawPtr::with_mut`.
+        pub fn with_mut<R, F: FnOnce(&mut T) -> R>(&mut self, f: F) -> Option<R> {
+            self.inner.with_mut(f)
+        }
+
+        /// Replaces each element with the maximum of itself and every element before it. See 
+        /// `MutRawPtr::running_max`.
+        pub fn running_max(&mut self) -> Option<()>
+        where T: Ord
+        {
+            self.inner.running_max()
+        }
+    }
+
+    /// The error returned by `MutRawPtr::try_fill_with`.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum FillError<E> {
+        /// The pointer is null or misaligned.
+        InvalidPtr,
+        /// The fill function failed at `index` with `error`.
+        Source { index: usize, error: E },
+    }
+
+    impl<E: std::fmt::Display> std::fmt::Display for FillError<E> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                FillError::InvalidPtr => write!(f, "box_raw_ptr Err: Invalid Pointer"),
+                FillError::Source { index, error } => write!(f, "box_raw_ptr Err: Fill Failed At Index {}: {}", index, error),
+            }
+        }
+    }
+
+    impl<E: std::error::Error> std::error::Error for FillError<E> {}
+
+    /// The iterator returned by `MutRawPtr::into_draining_iter`.
+    /// 
+    /// The memory block is dropped, and therefore freed if owned, once the last element is yielded or the 
+    /// iterator itself is dropped.
+    struct DrainingIter<T> 
+    where  T: Sized + Copy + Send + Sync
+    {
+        block: Option<MutRawPtr<T>>,
+        index: usize,
+    }
+
+    impl<T: Sized + Copy + Send + Sync> Iterator for DrainingIter<T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            let block: &MutRawPtr<T> = self.block.as_ref()?;
+            if self.index >= block.memory_length {
+                self.block = None;
+                return None;
+            }
+            let value: T = unsafe { *block.ptr.add(self.index) };
+            self.index += 1;
+            if self.index >= block.memory_length {
+                self.block = None;
+            }
+            Some(value)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining: usize = self.block.as_ref().map_or(0, |block| block.memory_length.saturating_sub(self.index));
+            (remaining, Some(remaining))
+        }
+    }
+}
+
+pub mod ring_raw_ptr {
+    use super::mut_raw_ptr::MutRawPtr;
+
+    /// A fixed-capacity FIFO ring buffer built on top of a `MutRawPtr` memory block.
+    /// 
+    /// `RingRawPtr` tracks a head index and an element count over the block, wrapping around at 
+    /// `memory_length`. It takes ownership of the `MutRawPtr`, so the memory block is released when the 
+    /// ring is dropped.
+    ///
+    /// Fields:
+    /// - `block: MutRawPtr<T>`: The memory block backing the ring.
+    /// - `head: usize`: The zero-based index of the oldest element.
+    /// - `len: usize`: The number of elements currently stored.
+    pub struct RingRawPtr<T> 
+    where  T: Sized + Copy + Send + Sync
+    {
+        block: MutRawPtr<T>,
+        head: usize,
+        len: usize,
+    }
+
+    impl<T: Sized + Copy + Send + Sync> RingRawPtr<T> {
+        /// Creates a new, empty `RingRawPtr` over the given memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let ring = RingRawPtr::new(mut_ptr);
+        /// ```
+        #[inline]
+        pub fn new(block: MutRawPtr<T>) -> Self {
+            Self { block, head: 0, len: 0 }
+        }
+
+        /// Pushes a value onto the back of the ring.
+        /// 
+        /// Returns `None` without writing if the ring is full or the underlying pointer is invalid.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// ring.push(42).unwrap();
+        /// ```
+        pub fn push(&mut self, value: T) -> Option<()> {
+            if !self.block.check_ptr() || self.is_full() {
+                return None;
+            }
+            let tail: usize = (self.head + self.len) % self.capacity();
+            unsafe { std::ptr::write(self.block.ptr.add(tail), value) };
+            self.len += 1;
+            Some(())
+        }
+
+        /// Pops the oldest value from the front of the ring.
+        /// 
+        /// Returns `None` if the ring is empty or the underlying pointer is invalid.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let value = ring.pop().unwrap();
+        /// ```
+        pub fn pop(&mut self) -> Option<T> {
+            if !self.block.check_ptr() || self.is_empty() {
+                return None;
+            }
+            let value: T = unsafe { std::ptr::read(self.block.ptr.add(self.head)) };
+            self.head = (self.head + 1) % self.capacity();
+            self.len -= 1;
+            Some(value)
+        }
+
+        /// Returns the number of elements currently stored in the ring.
+        #[inline]
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        /// Returns the maximum number of elements the ring can hold.
+        #[inline]
+        pub fn capacity(&self) -> usize {
+            self.block.check_memory_length()
+        }
+
+        /// Checks if the ring holds no elements.
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// Checks if the ring holds `capacity()` elements.
+        #[inline]
+        pub fn is_full(&self) -> bool {
+            self.len == self.capacity()
+        }
+
+        /// Consumes the ring and returns the underlying memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let mut_ptr = ring.into_inner();
+        /// ```
+        #[inline]
+        pub fn into_inner(self) -> MutRawPtr<T> {
+            self.block
+        }
+    }
+}
+
+pub mod owned_raw_ptr {
+    /// An owning, bounds-checked pointer to a C-allocated memory block of non-`Copy` elements.
+    /// 
+    /// `MutRawPtrOwned` is the counterpart of `MutRawPtr` for move-only types such as `String`. Elements are 
+    /// accessed by reference instead of by value, overwriting an element drops the previous value, and every 
+    /// element is dropped in place before the memory block is deallocated. Every element in 
+    /// `0..memory_length` is always initialized.
+    ///
+    /// Fields:
+    /// - `ptr: *mut T`: The base of the allocation.
+    /// - `memory_length: usize`: The number of live elements.
+    /// - `offset: usize`: The zero-based index of the current element.
+    pub struct MutRawPtrOwned<T> 
+    where  T: Sized + Send + Sync
+    {
+        pub(crate) ptr: *mut T,
+        pub(crate) memory_length: usize,
+        pub(crate) offset: usize,
+    }
+
+    impl<T: Sized + Send + Sync> MutRawPtrOwned<T> {
+        /// Moves the elements of `data` into a new memory block allocated with the C global allocator.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(MutRawPtrOwned<T>)`: An owning pointer to the moved elements.
+        /// - `None`: If `data` is empty, `T` is zero-sized, the offset is out of bounds, or the allocation 
+        ///   failed. `data` is dropped in that case.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let names = MutRawPtrOwned::from_vec(vec![String::from("a"), String::from("b")], 0).unwrap();
+        /// ```
+        pub fn from_vec(data: Vec<T>, offset: usize) -> Option<Self> {
+            let memory_length: usize = data.len();
+            if memory_length == 0 || std::mem::size_of::<T>() == 0 || offset >= memory_length {
+                return None;
+            }
+            let layout: std::alloc::Layout = std::alloc::Layout::array::<T>(memory_length).ok()?;
+            unsafe {
+                let alloc: *mut T = std::alloc::alloc(layout) as *mut T;
+                if alloc.is_null() {
+                    return None;
+                }
+                for (i, value) in data.into_iter().enumerate() {
+                    std::ptr::write(alloc.add(i), value);
+                }
+                #[cfg(feature = "debug-trace")]
+                super::debug_trace::record(alloc as usize, memory_length);
+                Some(Self { ptr: alloc, memory_length, offset })
+            }
+        }
+
+        /// Moves every element back out into a `Vec<T>` and deallocates the memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let names: Vec<String> = owned.into_vec();
+        /// ```
+        pub fn into_vec(self) -> Vec<T> {
+            let block: std::mem::ManuallyDrop<Self> = std::mem::ManuallyDrop::new(self);
+            let mut data: Vec<T> = Vec::with_capacity(block.memory_length);
+            unsafe {
+                for i in 0..block.memory_length {
+                    data.push(std::ptr::read(block.ptr.add(i)));
+                }
+                std::alloc::dealloc(block.ptr as *mut u8, block.layout());
+            }
+            #[cfg(feature = "debug-trace")]
+            super::debug_trace::forget(block.ptr as usize);
+            data
+        }
+
+        /* Layout of the allocation, valid because from_vec already built it for memory_length */
+        fn layout(&self) -> std::alloc::Layout {
+            std::alloc::Layout::array::<T>(self.memory_length).expect("Invalid alignment or size parameters.")
+        }
+
+        /// Checks if the current offset is within the bounds of the memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(owned.check_bounds());
+        /// ```
+        pub fn check_bounds(&self) -> bool {
+            self.offset < self.memory_length
+        }
+
+        /// Returns the current offset.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let offset: usize = owned.check_offset();
+        /// ```
+        #[inline]
+        pub fn check_offset(&self) -> usize {
+            self.offset
+        }
+
+        /// Returns the number of elements in the memory block.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let len: usize = owned.check_memory_length();
+        /// ```
+        #[inline]
+        pub fn check_memory_length(&self) -> usize {
+            self.memory_length
+        }
+
+        /// Changes the offset by a given index, if the resulting offset is within bounds.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// assert!(owned.change_offset(1).is_some());
+        /// ```
+        pub fn change_offset(&mut self, index: isize) -> Option<()> {
+            let new_offset: usize = self.offset.checked_add_signed(index)?;
+            if new_offset >= self.memory_length {
+                return None;
+            }
+            self.offset = new_offset;
+            Some(())
+        }
+
+        /// Returns a reference to the element at the current offset.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let name: &String = owned.access().unwrap();
+        /// ```
+        pub fn access(&self) -> Option<&T> {
+            self.get(self.offset)
+        }
+
+        /// Returns a mutable reference to the element at the current offset.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// owned.access_mut().unwrap().push('!');
+        /// ```
+        pub fn access_mut(&mut self) -> Option<&mut T> {
+            let offset: usize = self.offset;
+            self.get_mut(offset)
+        }
+
+        /// Returns a reference to the element at `index`, relative to the base of the allocation.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// let second: &String = owned.get(1).unwrap();
+        /// ```
+        pub fn get(&self, index: usize) -> Option<&T> {
+            if index >= self.memory_length {
+                return None;
+            }
+            Some( unsafe { &*self.ptr.add(index) } )
+        }
+
+        /// Returns a mutable reference to the element at `index`, relative to the base of the allocation.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// owned.get_mut(1).unwrap().clear();
+        /// ```
+        pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+            if index >= self.memory_length {
+                return None;
+            }
+            Some( unsafe { &mut *self.ptr.add(index) } )
+        }
+
+        /// Writes `src` at the current offset, dropping the value it replaces.
+        /// 
+        /// # Returns
+        /// 
+        /// - `Some(())`: If the value was written.
+        /// - `None`: If the offset is out of bounds. `src` is dropped in that case.
+        /// 
+        /// # Examples
+        /// 
+        /// ```rust
+        /// owned.write_ptr(String::from("c")).unwrap();
+        /// ```
+        pub fn write_ptr(&mut self, src: T) -> Option<()> {
+            if !self.check_bounds() {
+                return None;
+            }
+            /* Write the new value before dropping the old one, so a panicking destructor can't cause a double drop */
+            let old: T = unsafe { std::ptr::replace(self.ptr.add(self.offset), src) };
+            drop(old);
+            Some(())
+        }
+    }
+
+    unsafe impl<T: Sized + Send + Sync> Send for MutRawPtrOwned<T> {}
+
+    unsafe impl<T: Sized + Send + Sync> Sync for MutRawPtrOwned<T> {}
+
+    impl<T: Sized + Send + Sync> std::ops::Index<usize> for MutRawPtrOwned<T> {
+        type Output = T;
+
+        fn index(&self, index: usize) -> &T {
+            self.get(index).expect("box_raw_ptr Err: Index Out Of Bounds")
+        }
+    }
+
+    impl<T: Sized + Send + Sync> std::ops::IndexMut<usize> for MutRawPtrOwned<T> {
+        fn index_mut(&mut self, index: usize) -> &mut T {
+            self.get_mut(index).expect("box_raw_ptr Err: Index Out Of Bounds")
+        }
+    }
+
+    /// Drops every element in place, then deallocates the memory block.
+    impl<T: Sized + Send + Sync> Drop for MutRawPtrOwned<T> {
+        fn drop(&mut self) {
+            unsafe {
+                std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(self.ptr, self.memory_length));
+                std::alloc::dealloc(self.ptr as *mut u8, self.layout());
+            }
+            #[cfg(feature = "debug-trace")]
+            super::debug_trace::forget(self.ptr as usize);
+        }
+    }
+}
+
+pub mod integer {
+    /// Integer operations used by the arithmetic methods of `ConstRawPtr` and `MutRawPtr`.
+    /// 
+    /// `Integer` is implemented for every primitive integer type and forwards to the inherent method of the 
+    /// same name, so the overflow behavior of each operation matches the standard library.
+    pub trait Integer: Sized + Copy + Send + Sync {
+        /// Wrapping (modular) addition, see `i32::wrapping_add`.
+        fn wrapping_add(self, rhs: Self) -> Self;
+
+        /// Saturating addition, clamping at the numeric bounds, see `i32::saturating_add`.
+        fn saturating_add(self, rhs: Self) -> Self;
+
+        /// Absolute difference, see `i32::abs_diff`.
+        /// 
+        /// For signed types the difference is cast back to `Self`, so it wraps when it exceeds `Self::MAX`.
+        fn abs_diff(self, rhs: Self) -> Self;
+    }
+
+    macro_rules! impl_integer {
+        ($($t:ty),*) => {
+            $(
+                impl Integer for $t {
+                    #[inline]
+                    fn wrapping_add(self, rhs: Self) -> Self {
+                        <$t>::wrapping_add(self, rhs)
+                    }
+
+                    #[inline]
+                    fn saturating_add(self, rhs: Self) -> Self {
+                        <$t>::saturating_add(self, rhs)
+                    }
+
+                    #[inline]
+                    fn abs_diff(self, rhs: Self) -> Self {
+                        <$t>::abs_diff(self, rhs) as $t
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+    /// Integers with a matching standard library atomic type, used by `MutRawPtr::atomic_fetch_add_at`.
+    pub trait AtomicInteger: Integer {
+        /// Atomically adds `delta` to the value at `ptr` and returns the previous value.
+        /// 
+        /// Returns `None` without touching memory if `ptr` is not aligned for the atomic type.
+        /// 
+        /// # Safety
+        /// 
+        /// `ptr` must be valid for reads and writes, and every concurrent access to it must be atomic.
+        unsafe fn atomic_fetch_add(ptr: *mut Self, delta: Self, ordering: std::sync::atomic::Ordering) -> Option<Self>;
+    }
+
+    macro_rules! impl_atomic_integer {
+        ($($t:ty => $atomic:ty),*) => {
+            $(
+                impl AtomicInteger for $t {
+                    #[inline]
+                    unsafe fn atomic_fetch_add(ptr: *mut Self, delta: Self, ordering: std::sync::atomic::Ordering) -> Option<Self> {
+                        if !(ptr as usize).is_multiple_of(std::mem::align_of::<$atomic>()) {
+                            return None;
+                        }
+                        Some(<$atomic>::from_ptr(ptr).fetch_add(delta, ordering))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_atomic_integer!(
+        i8 => std::sync::atomic::AtomicI8, i16 => std::sync::atomic::AtomicI16, i32 => std::sync::atomic::AtomicI32,
+        isize => std::sync::atomic::AtomicIsize, u8 => std::sync::atomic::AtomicU8, u16 => std::sync::atomic::AtomicU16,
+        u32 => std::sync::atomic::AtomicU32, usize => std::sync::atomic::AtomicUsize
+    );
+
+    #[cfg(target_has_atomic = "64")]
+    impl_atomic_integer!(i64 => std::sync::atomic::AtomicI64, u64 => std::sync::atomic::AtomicU64);
+}
+
+pub mod error {
+    /// The error returned by the `try_` methods of `ConstRawPtr` and `MutRawPtr`.
+    /// 
+    /// Unlike the `Option` returned by the other methods, each variant tells why the operation failed.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum BoxRawPtrError {
+        /// The pointer is null.
+        Null,
+        /// The pointer is not aligned to `T`.
+        Misaligned,
+        /// The offset or index is outside the memory block.
+        OutOfBounds,
+        /// The memory length is zero.
+        ZeroLength,
+        /// The size of the memory block overflows a `Layout`.
+        LayoutOverflow,
+    }
+
+    impl std::fmt::Display for BoxRawPtrError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                BoxRawPtrError::Null => write!(f, "box_raw_ptr Err: Null Pointer"),
+                BoxRawPtrError::Misaligned => write!(f, "box_raw_ptr Err: Memory Not Aligned"),
+                BoxRawPtrError::OutOfBounds => write!(f, "box_raw_ptr Err: Offset Is Not Within Bounds"),
+                BoxRawPtrError::ZeroLength => write!(f, "box_raw_ptr Err: Zero Memory Length"),
+                BoxRawPtrError::LayoutOverflow => write!(f, "box_raw_ptr Err: Layout Overflow"),
+            }
+        }
+    }
+
+    impl std::error::Error for BoxRawPtrError {}
+}
+
+/* 
+Global registry of live c_malloc allocations
+Enabled with the debug-trace feature to track down leaked memory blocks
+Shared between threads, since a MutRawPtr may be dropped on another thread than it was allocated on
+*/
+#[cfg(feature = "debug-trace")]
+pub mod debug_trace {
+    use std::sync::{Mutex, MutexGuard};
+
+    static LIVE_ALLOCATIONS: Mutex<Vec<(usize, usize)>> = Mutex::new(Vec::new());
+
+    /* Locks the registry, a panic while it was held can't leave it inconsistent */
+    fn live() -> MutexGuard<'static, Vec<(usize, usize)>> {
+        LIVE_ALLOCATIONS.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Records a memory block allocated by `c_malloc`.
+    pub(crate) fn record(address: usize, memory_length: usize) {
+        live().push((address, memory_length));
+    }
+
+    /// Removes a memory block from the registry once it has been deallocated.
+    pub(crate) fn forget(address: usize) {
+        live().retain(|&(addr, _)| addr != address);
+    }
+
+    /// Lists the memory blocks allocated by `c_malloc` on any thread that have not been freed yet.
+    /// 
+    /// Each entry is the `(base address, memory length)` of a live allocation, in allocation order. A block is 
+    /// removed from the list when an owning `ConstRawPtr` or `MutRawPtr` deallocates it on drop.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// for (address, memory_length) in box_raw_ptr::debug_trace::report_live_allocations() {
+    ///     println!("leaked {} elements at {:x}", memory_length, address);
+    /// }
+    /// ```
+    pub fn report_live_allocations() -> Vec<(usize, usize)> {
+        live().clone()
+    }
+}
+
+/* 
+Serde support for ConstRawPtr and MutRawPtr
+Enabled with the serde feature, the elements are serialized by value and deserialized into a new c_malloc block
+*/
+#[cfg(feature = "serde")]
+pub mod serde_impls {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde::de::Error as DeError;
+    use serde::ser::{Error as SerError, SerializeStruct};
+    use super::const_raw_ptr::ConstRawPtr;
+    use super::error::BoxRawPtrError;
+    use super::mut_raw_ptr::MutRawPtr;
+
+    /* Owned form of a memory block used while deserializing */
+    #[derive(Deserialize)]
+    struct RawPtrRepr<T> {
+        memory_length: usize,
+        offset: usize,
+        data: Vec<T>,
+    }
+
+    impl<T> RawPtrRepr<T> {
+        /* Checks the decoded fields describe a block c_malloc can allocate */
+        fn validate<E: DeError>(&self) -> Result<(), E> {
+            if self.data.len() != self.memory_length {
+                return Err(E::invalid_length(self.data.len(), &"memory_length elements"));
+            }
+            if self.memory_length == 0 {
+                return Err(E::custom(BoxRawPtrError::ZeroLength));
+            }
+            if self.offset >= self.memory_length {
+                return Err(E::custom(BoxRawPtrError::OutOfBounds));
+            }
+            Ok(())
+        }
+    }
+
+    /* Serializes the memory length, offset and elements of a valid memory block */
+    fn serialize_block<S: Serializer, T: Serialize>(name: &'static str, memory_length: usize, offset: usize, data: Option<&[T]>, is_null: bool, serializer: S) -> Result<S::Ok, S::Error> {
+        let data: &[T] = match data {
+            Some(data) => data,
+            None => return Err(S::Error::custom(if is_null { BoxRawPtrError::Null } else { BoxRawPtrError::Misaligned })),
+        };
+        let mut state = serializer.serialize_struct(name, 3)?;
+        state.serialize_field("memory_length", &memory_length)?;
+        state.serialize_field("offset", &offset)?;
+        state.serialize_field("data", data)?;
+        state.end()
+    }
+
+    /// Serializes the memory length, offset and elements of the memory block.
+    /// 
+    /// The address is not serialized. A null or misaligned pointer fails to serialize.
+    impl<T: Sized + Copy + Send + Sync + Serialize> Serialize for ConstRawPtr<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_block("ConstRawPtr", self.memory_length, self.offset, self.as_slice(), self.is_null(), serializer)
+        }
+    }
+
+    /// Deserializes into a new owned memory block allocated with `c_malloc`.
+    impl<'de, T: Sized + Copy + Send + Sync + Deserialize<'de>> Deserialize<'de> for ConstRawPtr<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let repr: RawPtrRepr<T> = RawPtrRepr::deserialize(deserializer)?;
+            repr.validate()?;
+            let block: ConstRawPtr<T> = unsafe { ConstRawPtr::c_malloc(repr.memory_length, repr.offset) }
+                .ok_or_else(|| D::Error::custom(BoxRawPtrError::Null))?;
+            unsafe { std::ptr::copy_nonoverlapping(repr.data.as_ptr(), block.ptr as *mut T, repr.memory_length) };
+            Ok(block)
+        }
+    }
+
+    /// Serializes the memory length, offset and elements of the memory block.
+    /// 
+    /// The address is not serialized. A null or misaligned pointer fails to serialize.
+    impl<T: Sized + Copy + Send + Sync + Serialize> Serialize for MutRawPtr<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_block("MutRawPtr", self.memory_length, self.offset, self.as_slice(), self.is_null(), serializer)
+        }
+    }
+
+    /// Deserializes into a new owned memory block allocated with `c_malloc`.
+    impl<'de, T: Sized + Copy + Send + Sync + Deserialize<'de>> Deserialize<'de> for MutRawPtr<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let repr: RawPtrRepr<T> = RawPtrRepr::deserialize(deserializer)?;
+            repr.validate()?;
+            let block: MutRawPtr<T> = unsafe { MutRawPtr::c_malloc(repr.memory_length, repr.offset) }
+                .ok_or_else(|| D::Error::custom(BoxRawPtrError::Null))?;
+            unsafe { std::ptr::copy_nonoverlapping(repr.data.as_ptr(), block.ptr, repr.memory_length) };
+            Ok(block)
+        }
+    }
+}
+
+#[cfg(test)]
 mod box_raw_ptr_tests {
      use super::{const_raw_ptr::ConstRawPtr, mut_raw_ptr::MutRawPtr};
 
+    /* Allocates a block using c_malloc and copies data into it */
+    fn const_block<T: Sized + Copy + Send + Sync>(data: &[T]) -> ConstRawPtr<T> {
+        let block: ConstRawPtr<T> = unsafe { ConstRawPtr::c_malloc(data.len(), 0) }.unwrap();
+        unsafe { std::ptr::copy_nonoverlapping(data.as_ptr(), block.ptr as *mut T, data.len()) };
+        block
+    }
+
+    /* Allocates a block using c_malloc and copies data into it */
+    fn mut_block<T: Sized + Copy + Send + Sync>(data: &[T]) -> MutRawPtr<T> {
+        let block: MutRawPtr<T> = unsafe { MutRawPtr::c_malloc(data.len(), 0) }.unwrap();
+        unsafe { std::ptr::copy_nonoverlapping(data.as_ptr(), block.ptr, data.len()) };
+        block
+    }
+
+    /* Copies every element of a block into a Vec for comparison */
+    fn mut_contents<T: Sized + Copy + Send + Sync>(ptr: &MutRawPtr<T>) -> Vec<T> {
+        (0..ptr.memory_length).map(|i| unsafe { *ptr.ptr.add(i) }).collect()
+    }
+
+    #[test]
+    fn c_allocator_test() -> () {
+        /* Tests If Allocator Works */
+        let alloc: *mut i32 = unsafe { std::alloc::alloc(std::alloc::Layout::new::<i32>()) as *mut i32 };
+        let mut ptr = MutRawPtr::new(alloc, 1, 0);
+        unsafe { ptr.set_owned(true) };
+    }
+
+    #[test]
+    fn c_alloc_test() -> () {
+        let _safe_ptr: ConstRawPtr<i32> = unsafe { ConstRawPtr::c_malloc(1, 0) }.unwrap();
+    }
+
+    #[test]
+    fn dot_test() {
+        let a: ConstRawPtr<i32> = const_block(&[1, 2, 3]);
+        let b: ConstRawPtr<i32> = const_block(&[4, 5, 6]);
+        assert_eq!(a.dot(&b), Some(32));
+    }
+
+    #[test]
+    fn nul_terminated_len_test() {
+        let mut ptr: ConstRawPtr<i32> = const_block(&[1, 2, 3, 0, 5, 6]);
+        assert_eq!(ptr.nul_terminated_len(), Some(3));
+        ptr.change_offset(4).unwrap();
+        assert_eq!(ptr.nul_terminated_len(), Some(2));
+    }
+
+    #[test]
+    fn xor_with_test() {
+        let mut data: MutRawPtr<u8> = mut_block(&[0x12, 0x34, 0x56, 0x78]);
+        let key: ConstRawPtr<u8> = const_block(&[0xFF, 0x0F, 0xF0]);
+        data.xor_with(&key).unwrap();
+        assert_eq!(mut_contents(&data), vec![0xED, 0x3B, 0xA6, 0x78]);
+        data.xor_with(&key).unwrap();
+        assert_eq!(mut_contents(&data), vec![0x12, 0x34, 0x56, 0x78]);
+    }
+
+    #[test]
+    fn rev_view_test() {
+        let ptr: ConstRawPtr<i32> = const_block(&[1, 2, 3, 4]);
+        let view = ptr.rev_view();
+        assert_eq!(view.read_at(0), Some(4));
+        assert_eq!(view.read_at(3), Some(1));
+        assert_eq!(view.read_at(4), None);
+        assert_eq!(view.iter().collect::<Vec<i32>>(), vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn running_max_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[3, 1, 4, 1, 5]);
+        ptr.running_max().unwrap();
+        assert_eq!(mut_contents(&ptr), vec![3, 3, 4, 4, 5]);
+    }
+
+    #[test]
+    fn to_hex_string_test() {
+        let ptr: ConstRawPtr<u8> = const_block(&[0x00, 0x0f, 0xa5, 0xff]);
+        assert_eq!(ptr.to_hex_string(), Some(String::from("000fa5ff")));
+        assert_eq!(ConstRawPtr::<u8>::nullptr().to_hex_string(), None);
+    }
+
+    #[test]
+    fn from_hex_string_test() {
+        let ptr: MutRawPtr<u8> = MutRawPtr::from_hex_string("00fFa510").unwrap();
+        assert_eq!(ptr.check_memory_length(), 4);
+        assert_eq!(mut_contents(&ptr), vec![0x00, 0xff, 0xa5, 0x10]);
+        assert!(MutRawPtr::from_hex_string("abc").is_none());
+        assert!(MutRawPtr::from_hex_string("zz").is_none());
+        assert!(MutRawPtr::from_hex_string("+1").is_none());
+    }
+
+    #[test]
+    fn resize_preserving_offset_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3, 4]);
+        ptr.change_offset(2).unwrap();
+        assert_eq!(ptr.resize_preserving_offset(6, 9), Some(false));
+        assert_eq!(ptr.check_offset(), 2);
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 3, 4, 9, 9]);
+
+        assert_eq!(ptr.resize_preserving_offset(2, 9), Some(true));
+        assert_eq!(ptr.check_offset(), 1);
+        assert_eq!(mut_contents(&ptr), vec![1, 2]);
+        assert!(ptr.check_bounds());
+
+        /* Non-owned memory is never reallocated */
+        let mut value: i32 = 1;
+        let mut borrowed: MutRawPtr<i32> = MutRawPtr::new(&mut value as *mut i32, 1, 0);
+        assert_eq!(borrowed.resize_preserving_offset(4, 0), None);
+    }
+
+    #[test]
+    fn as_reader_test() {
+        use std::io::Read;
+
+        let ptr: ConstRawPtr<u8> = const_block(b"box_raw_ptr");
+        let mut buf: Vec<u8> = Vec::new();
+        ptr.as_reader().unwrap().read_to_end(&mut buf).unwrap();
+        assert_eq!(buf, b"box_raw_ptr".to_vec());
+    }
+
+    #[test]
+    fn as_writer_test() {
+        use std::io::Write;
+
+        let mut ptr: MutRawPtr<u8> = mut_block(&[0; 6]);
+        {
+            let mut writer = ptr.as_writer().unwrap();
+            write!(writer, "id={}", 42).unwrap();
+            assert!(write!(writer, "overflow").is_err());
+        }
+        assert_eq!(mut_contents(&ptr), b"id=42o".to_vec());
+    }
+
+    #[test]
+    fn dedup_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 1, 2, 3, 3, 3, 1]);
+        ptr.change_offset(6).unwrap();
+        assert_eq!(ptr.dedup(), Some(4));
+        assert_eq!(ptr.check_memory_length(), 4);
+        assert_eq!(ptr.check_offset(), 3);
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn ring_raw_ptr_test() {
+        let mut ring = mut_block(&[0i32; 3]).into_ring();
+        assert!(ring.is_empty());
+        assert_eq!(ring.pop(), None);
+
+        /* Fill the ring */
+        ring.push(1).unwrap();
+        ring.push(2).unwrap();
+        ring.push(3).unwrap();
+        assert!(ring.is_full());
+        assert_eq!(ring.push(4), None);
+
+        /* Wrap around */
+        assert_eq!(ring.pop(), Some(1));
+        assert_eq!(ring.pop(), Some(2));
+        ring.push(4).unwrap();
+        ring.push(5).unwrap();
+        assert_eq!(ring.len(), 3);
+
+        /* Confirm FIFO order */
+        assert_eq!(ring.pop(), Some(3));
+        assert_eq!(ring.pop(), Some(4));
+        assert_eq!(ring.pop(), Some(5));
+        assert_eq!(ring.pop(), None);
+    }
+
+    #[test]
+    fn remaining_to_vec_test() {
+        let mut ptr: ConstRawPtr<u8> = const_block(&[10, 20, 30, 40, 50]);
+        assert_eq!(ptr.remaining_to_vec(), Some(vec![10, 20, 30, 40, 50]));
+        ptr.change_offset(2).unwrap();
+        assert_eq!(ptr.remaining_to_vec(), Some(vec![30, 40, 50]));
+        assert_eq!(ConstRawPtr::<u8>::nullptr().remaining_to_vec(), None);
+    }
+
+    #[test]
+    fn count_ones_test() {
+        let ptr: ConstRawPtr<u8> = const_block(&[0b0000_0000, 0b1000_0001, 0xFF, 0b0101_0000]);
+        assert_eq!(ptr.count_ones(), Some(12));
+        assert_eq!(ConstRawPtr::<u8>::nullptr().count_ones(), None);
+    }
+
+    #[test]
+    fn argmax_argmin_test() {
+        let unique: ConstRawPtr<i32> = const_block(&[3, -1, 9, 4]);
+        assert_eq!(unique.argmax(), Some(2));
+        assert_eq!(unique.argmin(), Some(1));
+
+        let ties: ConstRawPtr<i32> = const_block(&[2, 7, 1, 7, 1]);
+        assert_eq!(ties.argmax(), Some(1));
+        assert_eq!(ties.argmin(), Some(2));
+
+        assert_eq!(ConstRawPtr::<i32>::nullptr().argmax(), None);
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Data {
+        a: i32,
+        b: f64,
+    }
+
+    #[test]
+    fn project_test() {
+        let mut ptr: MutRawPtr<Data> = mut_block(&[Data { a: 1, b: 1.5 }, Data { a: 2, b: 2.5 }]);
+        ptr.change_offset(1).unwrap();
+        {
+            let mut field = unsafe { ptr.project::<f64>(std::mem::offset_of!(Data, b)) }.unwrap();
+            field.write_ptr(9.75).unwrap();
+        }
+        assert_eq!(mut_contents(&ptr), vec![Data { a: 1, b: 1.5 }, Data { a: 2, b: 9.75 }]);
+
+        /* Field does not fit or is misaligned */
+        assert!(unsafe { ptr.project::<f64>(std::mem::size_of::<Data>()) }.is_none());
+        assert!(unsafe { ptr.project::<f64>(1) }.is_none());
+    }
+
+    #[test]
+    fn insert_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 4, 5, 0]);
+        ptr.change_memory_length(4).unwrap();
+        assert_eq!(ptr.check_capacity(), 5);
+
+        ptr.insert(2, 3).unwrap();
+        assert_eq!(ptr.check_memory_length(), 5);
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 3, 4, 5]);
+
+        /* At capacity */
+        assert_eq!(ptr.insert(0, 0), None);
+    }
+
+    #[test]
+    fn insert_out_of_range_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3]);
+        ptr.change_memory_length(2).unwrap();
+        assert_eq!(ptr.insert(3, 9), None);
+        ptr.insert(2, 9).unwrap();
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 9]);
+    }
+
+    #[test]
+    fn remove_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3, 4, 5]);
+        assert_eq!(ptr.remove(2), Some(3));
+        assert_eq!(ptr.check_memory_length(), 4);
+        assert_eq!(ptr.check_capacity(), 5);
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 4, 5]);
+        assert_eq!(ptr.remove(4), None);
+    }
+
+    #[test]
+    fn swap_remove_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3, 4, 5]);
+        assert_eq!(ptr.swap_remove(1), Some(2));
+        assert_eq!(ptr.check_memory_length(), 4);
+        assert_eq!(mut_contents(&ptr), vec![1, 5, 3, 4]);
+        assert_eq!(ptr.swap_remove(3), Some(4));
+        assert_eq!(mut_contents(&ptr), vec![1, 5, 3]);
+        assert_eq!(ptr.swap_remove(3), None);
+    }
+
+    #[test]
+    fn from_array_mut_test() {
+        let mut arr: [i32; 4] = [4, 1, 7, 2];
+        {
+            let mut view = MutRawPtr::from_array_mut(&mut arr);
+            assert_eq!(view.check_memory_length(), 4);
+            view.running_max().unwrap();
+            view.change_offset(1).unwrap();
+            view.write_ptr(5).unwrap();
+            assert_eq!(view.as_slice().unwrap(), &[4, 5, 7, 7]);
+        }
+        assert_eq!(arr, [4, 5, 7, 7]);
+    }
+
+    #[test]
+    fn histogram_test() {
+        let ptr: ConstRawPtr<u8> = const_block(&[0, 7, 7, 255, 7, 0]);
+        let histogram: [usize; 256] = ptr.histogram().unwrap();
+        assert_eq!(histogram[0], 2);
+        assert_eq!(histogram[7], 3);
+        assert_eq!(histogram[255], 1);
+        assert_eq!(histogram.iter().sum::<usize>(), 6);
+    }
+
+    #[test]
+    fn shannon_entropy_test() {
+        let data: Vec<u8> = (0..=255).cycle().take(1024).collect();
+        let uniform: ConstRawPtr<u8> = const_block(&data);
+        assert!((uniform.shannon_entropy().unwrap() - 8.0).abs() < 1e-9);
+
+        let constant: ConstRawPtr<u8> = const_block(&[0xAA; 64]);
+        assert_eq!(constant.shannon_entropy(), Some(0.0));
+    }
+
+    #[test]
+    fn clamp_each_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[-5, 0, 7, 10, 42]);
+        ptr.clamp_each(0, 10).unwrap();
+        assert_eq!(mut_contents(&ptr), vec![0, 0, 7, 10, 10]);
+    }
+
+    #[test]
+    fn aliases_slice_test() {
+        let data: [i32; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let mut ptr: ConstRawPtr<i32> = ConstRawPtr::new(data.as_ptr(), 4, 0);
+        unsafe { ptr.set_owned(false) };
+
+        assert!(ptr.aliases_slice(&data[2..6]));
+        assert!(ptr.aliases_slice(&data[..1]));
+        assert!(!ptr.aliases_slice(&data[4..]));
+        assert!(!ptr.aliases_slice(&[1, 2, 3, 4]));
+        assert!(!ptr.aliases_slice(&data[..0]));
+    }
+
+    #[test]
+    fn add_blocks_test() {
+        let mut ptr: MutRawPtr<u8> = mut_block(&[1, 2, u8::MAX, 250]);
+        let other: ConstRawPtr<u8> = const_block(&[10, 20, 1, 10, 99]);
+        ptr.add_blocks(&other).unwrap();
+        assert_eq!(mut_contents(&ptr), vec![11, 22, 0, 4]);
+    }
+
+    #[test]
+    fn read_from_end_test() {
+        let ptr: ConstRawPtr<i32> = const_block(&[1, 2, 3, 4]);
+        assert_eq!(ptr.read_from_end(0), Some(4));
+        assert_eq!(ptr.read_from_end(1), Some(3));
+        assert_eq!(ptr.read_from_end(4), None);
+    }
+
+    #[test]
+    fn zip_map_test() {
+        let a: ConstRawPtr<i32> = const_block(&[i32::MAX, 2, -3]);
+        let b: ConstRawPtr<i32> = const_block(&[1, 20, -30, 40]);
+        let sums: MutRawPtr<i64> = a.zip_map(&b, |x, y| x as i64 + y as i64).unwrap();
+        assert_eq!(mut_contents(&sums), vec![i32::MAX as i64 + 1, 22, -33]);
+    }
+
+    #[test]
+    fn sync_offset_from_base_test() {
+        let mut ptr: ConstRawPtr<u32> = const_block(&[1, 2, 3, 4]);
+        let base: *const u32 = ptr.as_non_null().unwrap().as_ptr();
+
+        /* C code advances its own copy of the pointer, leaving the offset behind */
+        let c_cursor: *const u32 = base.wrapping_add(2);
+        assert_eq!(ptr.check_offset(), 0);
+        ptr.sync_offset_from_base(c_cursor).unwrap();
+        assert_eq!(ptr.check_offset(), 2);
+        assert_eq!(ptr.access(), Some(3));
+
+        /* An offset moved out of step is restored from the cursor */
+        ptr.change_offset(-2).unwrap();
+        ptr.sync_offset_from_base(c_cursor).unwrap();
+        assert_eq!(ptr.check_offset(), 2);
+
+        /* Misaligned, before the base, or past the end */
+        assert!(ptr.sync_offset_from_base((base as usize + 1) as *const u32).is_none());
+        assert!(ptr.sync_offset_from_base(base.wrapping_sub(1)).is_none());
+        assert!(ptr.sync_offset_from_base(base.wrapping_add(4)).is_none());
+        assert_eq!(ptr.check_offset(), 2);
+
+        let mut mut_ptr: MutRawPtr<u32> = mut_block(&[1, 2]);
+        let c_cursor: *mut u32 = mut_ptr.as_non_null().unwrap().as_ptr().wrapping_add(1);
+        mut_ptr.sync_offset_from_base(c_cursor).unwrap();
+        assert_eq!(mut_ptr.access(), Some(2));
+    }
+
+    #[test]
+    fn try_fill_with_test() {
+        use super::mut_raw_ptr::FillError;
+
+        let mut ptr: MutRawPtr<i32> = mut_block(&[0; 5]);
+        let result = ptr.try_fill_with(|i| if i < 3 { Ok(i as i32 + 1) } else { Err("source exhausted") });
+        assert_eq!(result, Err(FillError::Source { index: 3, error: "source exhausted" }));
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 3, 0, 0]);
+
+        ptr.try_fill_with(|i| Ok::<i32, ()>(i as i32 * 10)).unwrap();
+        assert_eq!(mut_contents(&ptr), vec![0, 10, 20, 30, 40]);
+
+        let mut null: MutRawPtr<i32> = MutRawPtr::nullptr();
+        assert_eq!(null.try_fill_with(|_| Ok::<i32, ()>(0)), Err(FillError::InvalidPtr));
+    }
+
+    #[test]
+    fn field_column_test() {
+        let ptr: ConstRawPtr<Data> = const_block(&[
+            Data { a: 1, b: 0.5 },
+            Data { a: -2, b: 1.5 },
+            Data { a: 3, b: 2.5 },
+        ]);
+        assert_eq!(unsafe { ptr.field_column::<i32>(std::mem::offset_of!(Data, a)) }, Some(vec![1, -2, 3]));
+        assert_eq!(unsafe { ptr.field_column::<f64>(std::mem::offset_of!(Data, b)) }, Some(vec![0.5, 1.5, 2.5]));
+        assert_eq!(unsafe { ptr.field_column::<f64>(12) }, None);
+        assert_eq!(unsafe { ptr.field_column::<i32>(2) }, None);
+    }
+
+    #[test]
+    fn saturating_add_scalar_test() {
+        let mut ptr: MutRawPtr<i8> = mut_block(&[-100, 0, 20, i8::MAX]);
+        ptr.saturating_add_scalar(120).unwrap();
+        assert_eq!(mut_contents(&ptr), vec![20, 120, i8::MAX, i8::MAX]);
+        ptr.saturating_add_scalar(i8::MIN).unwrap();
+        assert_eq!(mut_contents(&ptr), vec![-108, -8, -1, -1]);
+        ptr.saturating_add_scalar(i8::MIN).unwrap();
+        assert_eq!(mut_contents(&ptr), vec![i8::MIN; 4]);
+    }
+
+    #[test]
+    fn snapshot_test() {
+        let mut ptr: ConstRawPtr<i32> = const_block(&[5, 6, 7]);
+        ptr.change_offset(1).unwrap();
+        let snapshot: ConstRawPtr<i32> = ptr.snapshot().unwrap();
+        assert_ne!(snapshot.ptr, ptr.ptr);
+        drop(ptr);
+        assert_eq!(snapshot.check_offset(), 1);
+        assert_eq!(snapshot.remaining_to_vec(), Some(vec![6, 7]));
+        assert_eq!(snapshot.read_from_end(2), Some(5));
+    }
+
+    #[test]
+    fn records_test() {
+        let bytes: Vec<u8> = [0x0102u16, 0xBEEF, 7].iter().flat_map(|r| r.to_ne_bytes()).chain([0xFF]).collect();
+        let ptr: ConstRawPtr<u8> = const_block(&bytes);
+        assert_eq!(unsafe { ptr.records::<u16>() }.unwrap().collect::<Vec<u16>>(), vec![0x0102, 0xBEEF, 7]);
+        assert_eq!(unsafe { ptr.records::<u64>() }.unwrap().count(), 0);
+    }
+
+    #[test]
+    fn longest_run_test() {
+        let ptr: ConstRawPtr<u8> = const_block(&[0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0]);
+        assert_eq!(ptr.longest_run(0), Some((2, 3)));
+        assert_eq!(ptr.longest_run(3), Some((9, 1)));
+        assert_eq!(ptr.longest_run(9), None);
+    }
+
+    #[test]
+    fn partition_in_place_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3, 4, 5, 6]);
+        assert_eq!(ptr.partition_in_place(|x| x % 2 == 0), Some(3));
+        assert_eq!(mut_contents(&ptr), vec![2, 4, 6, 1, 3, 5]);
+        assert_eq!(ptr.partition_in_place(|_| false), Some(0));
+        assert_eq!(mut_contents(&ptr), vec![2, 4, 6, 1, 3, 5]);
+        assert!(MutRawPtr::<i32>::nullptr().partition_in_place(|_| true).is_none());
+    }
+
+    #[cfg(feature = "debug-trace")]
+    #[test]
+    fn debug_trace_test() {
+        use super::debug_trace::report_live_allocations;
+
+        let leaked: MutRawPtr<i32> = unsafe { MutRawPtr::c_malloc(4, 0) }.unwrap();
+        assert!(report_live_allocations().contains(&(leaked.ptr as usize, 4)));
+
+        let freed: MutRawPtr<i32> = mut_block(&[1, 2, 3]);
+        let address: usize = freed.ptr as usize;
+        assert!(report_live_allocations().contains(&(address, 3)));
+        drop(freed);
+        assert!(!report_live_allocations().iter().any(|&(addr, _)| addr == address));
+
+        /* Release the leaked block on another thread so the test itself does not leak */
+        let address: usize = leaked.ptr as usize;
+        std::thread::spawn(move || drop(leaked)).join().unwrap();
+        assert!(!report_live_allocations().iter().any(|&(addr, _)| addr == address));
+    }
+
     #[test]
-    fn c_allocator_test() -> () {
-        /* Tests If Allocator Works */
-        let alloc: *mut i32 = unsafe { std::alloc::alloc(std::alloc::Layout::new::<i32>()) as *mut i32 };
-        let _ = MutRawPtr::new(alloc, 1, 1);
+    fn atomic_fetch_add_at_test() {
+        use std::sync::atomic::Ordering;
+
+        let counters: MutRawPtr<u64> = mut_block(&[0; 5]);
+        std::thread::scope(|scope| {
+            for t in 0..4 {
+                let counters: &MutRawPtr<u64> = &counters;
+                scope.spawn(move || {
+                    for _ in 0..1000 {
+                        /* Every thread only touches the counters atomically */
+                        unsafe { counters.atomic_fetch_add_at(t, 1, Ordering::Relaxed) }.unwrap();
+                        unsafe { counters.atomic_fetch_add_at(4, 1, Ordering::Relaxed) }.unwrap();
+                    }
+                });
+            }
+        });
+        assert_eq!(mut_contents(&counters), vec![1000, 1000, 1000, 1000, 4000]);
+        assert_eq!(unsafe { counters.atomic_fetch_add_at(5, 1, Ordering::Relaxed) }, None);
     }
 
     #[test]
-    fn c_alloc_test() -> () {
-        let t: *const i32 = ConstRawPtr::c_malloc(1).unwrap();
-        let _safe_ptr: ConstRawPtr<i32> = ConstRawPtr::new(t, 1, 1);
+    fn into_shards_test() {
+        let ptr: MutRawPtr<i32> = mut_block(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let shards: Vec<MutRawPtr<i32>> = ptr.into_shards(3).unwrap();
+        let contents: Vec<Vec<i32>> = shards.iter().map(mut_contents).collect();
+        assert_eq!(contents, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8, 9]]);
+        drop(shards);
+
+        assert!(mut_block(&[1, 2]).into_shards(0).is_none());
+        assert!(mut_block(&[1, 2]).into_shards(3).is_none());
+    }
+
+    #[test]
+    fn merge_sorted_test() {
+        let shards: Vec<ConstRawPtr<i32>> = vec![
+            const_block(&[1, 4, 9]),
+            const_block(&[2, 3, 10, 11]),
+            const_block(&[0, 4, 5]),
+        ];
+        let merged: MutRawPtr<i32> = MutRawPtr::merge_sorted(shards).unwrap();
+        assert_eq!(mut_contents(&merged), vec![0, 1, 2, 3, 4, 4, 5, 9, 10, 11]);
+
+        assert!(MutRawPtr::merge_sorted(vec![const_block(&[1]), ConstRawPtr::nullptr()]).is_none());
+    }
+
+    #[test]
+    fn pixel_at_test() {
+        /* 4x3 grid with one padding element per row */
+        let ptr: ConstRawPtr<u8> = const_block(&[
+            0, 1, 2, 3, 0xFF,
+            10, 11, 12, 13, 0xFF,
+            20, 21, 22, 23, 0xFF,
+        ]);
+        assert_eq!(ptr.pixel_at(0, 0, 5), Some(0));
+        assert_eq!(ptr.pixel_at(3, 1, 5), Some(13));
+        assert_eq!(ptr.pixel_at(2, 2, 5), Some(22));
+        assert_eq!(ptr.pixel_at(5, 0, 5), None);
+        assert_eq!(ptr.pixel_at(0, 3, 5), None);
+    }
+
+    #[test]
+    fn with_mut_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3]);
+        ptr.change_offset(1).unwrap();
+        let doubled: i32 = ptr.with_mut(|value| {
+            *value *= 10;
+            *value * 2
+        }).unwrap();
+        assert_eq!(doubled, 40);
+        assert_eq!(mut_contents(&ptr), vec![1, 20, 3]);
+        assert_eq!(MutRawPtr::<i32>::nullptr().with_mut(|value| *value), None);
+    }
+
+    #[test]
+    fn abs_diff_test() {
+        let a: ConstRawPtr<u8> = const_block(&[10, 200, 0, 255]);
+        let b: ConstRawPtr<u8> = const_block(&[15, 100, 255, 255, 1]);
+        let diff: MutRawPtr<u8> = a.abs_diff(&b).unwrap();
+        assert_eq!(mut_contents(&diff), vec![5, 100, 255, 0]);
+        assert!(a.abs_diff(&ConstRawPtr::nullptr()).is_none());
+    }
+
+    #[test]
+    fn is_sorted_test() {
+        assert_eq!(const_block(&[1, 2, 2, 5]).is_sorted(), Some(true));
+        assert_eq!(const_block(&[1, 3, 2]).is_sorted(), Some(false));
+        assert_eq!(const_block(&[7]).is_sorted(), Some(true));
+        assert_eq!(ConstRawPtr::<i32>::nullptr().is_sorted(), None);
+    }
+
+    #[test]
+    fn shift_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3, 4, 5]);
+        ptr.shift(2, 0).unwrap();
+        assert_eq!(mut_contents(&ptr), vec![0, 0, 1, 2, 3]);
+        ptr.shift(-1, 9).unwrap();
+        assert_eq!(mut_contents(&ptr), vec![0, 1, 2, 3, 9]);
+        ptr.shift(-10, 7).unwrap();
+        assert_eq!(mut_contents(&ptr), vec![7; 5]);
+    }
+
+    #[test]
+    fn fork_test() {
+        use super::const_raw_ptr::BorrowedConstRawPtr;
+
+        let ptr: ConstRawPtr<i32> = const_block(&[1, 2, 3]);
+        {
+            let mut cursor: BorrowedConstRawPtr<i32> = ptr.fork();
+            cursor.change_offset(2).unwrap();
+            assert_eq!(cursor.remaining_to_vec(), Some(vec![3]));
+            assert_eq!(ptr.check_offset(), 0);
+        }
+        /* The fork was dropped without freeing the block */
+        assert_eq!(ptr.remaining_to_vec(), Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn write_if_eq_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[5, 6]);
+        assert_eq!(ptr.write_if_eq(5, 50), Some(true));
+        assert_eq!(ptr.write_if_eq(5, 70), Some(false));
+        assert_eq!(mut_contents(&ptr), vec![50, 6]);
+        assert_eq!(MutRawPtr::<i32>::nullptr().write_if_eq(0, 1), None);
+    }
+
+    #[test]
+    fn layout_test() {
+        let ptr: ConstRawPtr<u32> = const_block(&[1, 2, 3, 4, 5]);
+        let layout: std::alloc::Layout = ptr.layout().unwrap();
+        assert_eq!(layout.size(), 20);
+        assert_eq!(layout.align(), 4);
+
+        let mut_ptr: MutRawPtr<f64> = mut_block(&[0.0; 3]);
+        assert_eq!(mut_ptr.layout(), std::alloc::Layout::from_size_align(24, 8).ok());
+        assert_eq!(ConstRawPtr::<u32>::nullptr().layout(), None);
+    }
+
+    #[test]
+    fn from_reader_test() {
+        let mut cursor = std::io::Cursor::new(vec![1u8, 2, 3, 4, 5]);
+        let ptr: MutRawPtr<u8> = MutRawPtr::from_reader(&mut cursor, 4).unwrap();
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 3, 4]);
+
+        /* Only one byte left */
+        assert!(MutRawPtr::from_reader(&mut cursor, 2).is_none());
+    }
+
+    #[test]
+    fn moving_average_test() {
+        let ptr: ConstRawPtr<i32> = const_block(&[1, 2, 3, 4, 5]);
+        assert_eq!(mut_contents(&ptr.moving_average(3).unwrap()), vec![2.0, 3.0, 4.0]);
+        assert_eq!(mut_contents(&ptr.moving_average(5).unwrap()), vec![3.0]);
+        assert!(ptr.moving_average(0).is_none());
+        assert!(ptr.moving_average(6).is_none());
+    }
+
+    #[test]
+    fn as_slice_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[3, 1, 2]);
+        ptr.change_offset(2).unwrap();
+        assert_eq!(ptr.as_slice().unwrap(), &[3, 1, 2]);
+        ptr.as_mut_slice().unwrap().sort();
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 3]);
+        assert_eq!(const_block(&[4, 5]).as_slice().unwrap(), &[4, 5]);
+        assert!(MutRawPtr::<i32>::nullptr().as_slice().is_none());
+    }
+
+    #[test]
+    fn with_corrected_length_test() {
+        let alloc: MutRawPtr<i32> = unsafe { MutRawPtr::c_malloc(5, 0) }.unwrap();
+        let mut ptr: MutRawPtr<i32> = MutRawPtr::new(alloc.ptr, 2, 1);
+        /* Correcting upward keeps the offset in bounds */
+        assert!(unsafe { ptr.with_corrected_length(5) }.is_some());
+        assert_eq!(ptr.memory_length, 5);
+        ptr.change_offset(2).unwrap();
+        /* The offset 3 no longer fits in a length of 3 */
+        assert!(unsafe { ptr.with_corrected_length(3) }.is_none());
+        assert_eq!(ptr.memory_length, 5);
+    }
+
+    #[test]
+    fn byte_ptr_round_trip_test() {
+        let ptr: MutRawPtr<i32> = mut_block(&[1, -2, 3]);
+        let bytes: MutRawPtr<u8> = ptr.into_byte_ptr();
+        assert_eq!(bytes.memory_length, 12);
+        assert_eq!(mut_contents(&bytes)[..4], 1i32.to_ne_bytes());
+        /* The block is freed once, by the pointer returned from from_byte_ptr */
+        let ptr: MutRawPtr<i32> = MutRawPtr::from_byte_ptr(bytes).unwrap();
+        assert_eq!(mut_contents(&ptr), vec![1, -2, 3]);
+        /* A byte length that isn't a multiple of the element size is rejected */
+        assert!(MutRawPtr::<i32>::from_byte_ptr(mut_block(&[0u8; 6])).is_none());
+    }
+
+    #[test]
+    fn to_vec_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3, 4, 5]);
+        ptr.change_offset(3).unwrap();
+        assert_eq!(ptr.to_vec().unwrap(), vec![1, 2, 3, 4, 5]);
+        /* The wrapper is still usable after the copy */
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 3, 4, 5]);
+        assert_eq!(const_block(&[7, 8]).to_vec().unwrap(), vec![7, 8]);
+        assert!(ConstRawPtr::<i32>::nullptr().to_vec().is_none());
+    }
+
+    #[test]
+    fn gather_test() {
+        let ptr: ConstRawPtr<i32> = const_block(&[10, 20, 30, 40]);
+        assert_eq!(ptr.gather(&[2, 0, 3]), Some(vec![30, 10, 40]));
+        assert_eq!(ptr.gather(&[]), Some(vec![]));
+        assert!(ptr.gather(&[1, 4]).is_none());
+    }
+
+    #[test]
+    fn iter_test() {
+        let ptr: ConstRawPtr<i32> = const_block(&[1, 2, 3, 4]);
+        assert_eq!(ptr.iter().collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+        assert_eq!(ptr.iter().rev().collect::<Vec<i32>>(), vec![4, 3, 2, 1]);
+        let mut iter = ptr.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!((iter.next(), iter.next_back()), (Some(1), Some(4)));
+        assert_eq!((iter.next(), iter.next_back()), (Some(2), Some(3)));
+        assert_eq!((iter.next(), iter.next_back()), (None, None));
+        assert_eq!(ConstRawPtr::<i32>::nullptr().iter().count(), 0);
+    }
+
+    #[test]
+    fn scatter_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[0, 0, 0, 0, 0]);
+        assert!(ptr.scatter(&[3, 0], &[7, 9]).is_some());
+        assert_eq!(mut_contents(&ptr), vec![9, 0, 0, 7, 0]);
+        /* Mismatched lengths and out of range indices write nothing */
+        assert!(ptr.scatter(&[1, 2], &[5]).is_none());
+        assert!(ptr.scatter(&[1, 5], &[5, 6]).is_none());
+        assert_eq!(mut_contents(&ptr), vec![9, 0, 0, 7, 0]);
+    }
+
+    #[test]
+    fn drop_layout_test() {
+        let mut ptr: MutRawPtr<i64> = mut_block(&[0; 8]);
+        assert_eq!(ptr.layout().unwrap(), std::alloc::Layout::array::<i64>(8).unwrap());
+        /* Shrinking the memory length keeps the 8 element layout used on drop */
+        assert!(ptr.change_memory_length(4).is_some());
+        assert_eq!(ptr.layout().unwrap(), std::alloc::Layout::array::<i64>(8).unwrap());
+        /* The memory length can't grow past the allocation */
+        assert!(ptr.change_memory_length(9).is_none());
+        assert!(ptr.change_memory_length(8).is_some());
+    }
+
+    #[test]
+    fn copy_from_slice_overlap_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3, 4, 5]);
+        /* A source slice over the same block is rejected without writing anything */
+        let src: &[i32] = unsafe { std::slice::from_raw_parts(ptr.ptr, 4) };
+        ptr.change_offset(1).unwrap();
+        assert!(ptr.copy_from_slice(src).is_none());
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn ownership_test() {
+        /* A pointer to stack memory must not be freed on drop */
+        let mut value: i32 = 5;
+        let ptr: MutRawPtr<i32> = MutRawPtr::new(&mut value as *mut i32, 1, 0);
+        assert!(!ptr.owned);
+        drop(ptr);
+        assert_eq!(value, 5);
+
+        assert!(unsafe { MutRawPtr::<i32>::c_malloc(2, 0) }.unwrap().owned);
+        /* A clone shares the block without freeing it a second time */
+        let block: MutRawPtr<i32> = mut_block(&[1, 2]);
+        let copy: MutRawPtr<i32> = block.clone();
+        assert!(!copy.owned);
+        drop(copy);
+        assert_eq!(mut_contents(&block), vec![1, 2]);
+        assert!(unsafe { ConstRawPtr::<i32>::c_malloc(2, 3) }.is_none());
+        assert!(unsafe { ConstRawPtr::<i32>::c_malloc(0, 0) }.is_none());
+    }
+
+    #[test]
+    fn read_at_write_at_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3]);
+        ptr.change_offset(1).unwrap();
+        assert_eq!(ptr.read_at(2), Some(3));
+        assert!(ptr.write_at(0, 9).is_some());
+        assert!(ptr.write_at(3, 9).is_none());
+        assert_eq!(mut_contents(&ptr), vec![9, 2, 3]);
+        /* The offset is left where it was */
+        assert_eq!(ptr.offset, 1);
+        assert_eq!(const_block(&[4, 5]).read_at(2), None);
+    }
+
+    #[test]
+    fn into_draining_iter_test() {
+        let ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3, 4]);
+        assert_eq!(ptr.into_draining_iter().collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+
+        /* Dropping the iterator halfway frees the block once */
+        let ptr: MutRawPtr<i32> = mut_block(&[5, 6, 7, 8]);
+        let _address: usize = ptr.ptr as usize;
+        let mut iter = ptr.into_draining_iter();
+        assert_eq!((iter.next(), iter.next()), (Some(5), Some(6)));
+        drop(iter);
+        #[cfg(feature = "debug-trace")]
+        assert!(!super::debug_trace::report_live_allocations().iter().any(|&(addr, _)| addr == _address));
+
+        assert_eq!(MutRawPtr::<i32>::nullptr().into_draining_iter().count(), 0);
+
+        /* A block emptied by remove yields nothing */
+        let mut ptr: MutRawPtr<i32> = mut_block(&[9]);
+        assert_eq!(ptr.remove(0), Some(9));
+        assert_eq!(ptr.check_memory_length(), 0);
+        assert_eq!(ptr.into_draining_iter().count(), 0);
+    }
+
+    #[test]
+    fn fill_test() {
+        let mut ptr: MutRawPtr<u16> = unsafe { MutRawPtr::c_malloc(16, 3) }.unwrap();
+        assert!(ptr.fill(0xABCD).is_some());
+        assert_eq!(mut_contents(&ptr), vec![0xABCD; 16]);
+        assert_eq!(ptr.offset, 3);
+        assert!(MutRawPtr::<u16>::nullptr().fill(1).is_none());
+    }
+
+    #[test]
+    fn copy_from_slice_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[0; 5]);
+        assert!(ptr.copy_from_slice(&[1, 2, 3, 4, 5]).is_some());
+        assert_eq!(ptr.to_vec().unwrap(), vec![1, 2, 3, 4, 5]);
+        ptr.change_offset(3).unwrap();
+        assert!(ptr.copy_from_slice(&[8, 9]).is_some());
+        /* Two elements don't fit after offset 4, so nothing is written */
+        ptr.change_offset(1).unwrap();
+        assert!(ptr.copy_from_slice(&[6, 7]).is_none());
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 3, 8, 9]);
+
+        /* A slice of the block itself is rejected */
+        let alias: MutRawPtr<i32> = ptr.clone();
+        ptr.set_offset(0).unwrap();
+        assert!(ptr.aliases_slice(&alias.as_slice().unwrap()[3..]));
+        assert!(ptr.copy_from_slice(&alias.as_slice().unwrap()[3..]).is_none());
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 3, 8, 9]);
+    }
+
+    #[test]
+    fn volatile_test() {
+        let mut ptr: MutRawPtr<u32> = mut_block(&[0, 0, 0]);
+        ptr.change_offset(1).unwrap();
+        assert!(ptr.write_volatile(0xFF).is_some());
+        assert_eq!(ptr.read_volatile(), Some(0xFF));
+        assert_eq!(mut_contents(&ptr), vec![0, 0xFF, 0]);
+        assert_eq!(const_block(&[7u32]).read_volatile(), Some(7));
+    }
+
+    #[test]
+    fn unaligned_test() {
+        let mut bytes: [u8; 9] = [0; 9];
+        /* Two u32 values starting at an odd address */
+        let packed: *mut u32 = unsafe { bytes.as_mut_ptr().add(1) } as *mut u32;
+        let mut ptr: MutRawPtr<u32> = MutRawPtr::new_unaligned(packed, 2, 1);
+        /* The aligned accessors refuse the pointer */
+        assert!(ptr.read_volatile().is_none());
+        assert!(ptr.write_unaligned(0x01020304).is_some());
+        assert_eq!(ptr.access_unaligned(), Some(0x01020304));
+        assert_eq!(bytes[5..9], 0x01020304u32.to_ne_bytes());
+        let ptr: ConstRawPtr<u32> = ConstRawPtr::new_unaligned(packed as *const u32, 2, 1);
+        assert_eq!(ptr.access_unaligned(), Some(0x01020304));
+    }
+
+    #[test]
+    fn box_raw_ptr_error_test() {
+        use super::error::BoxRawPtrError;
+
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3]);
+        assert_eq!(ptr.try_change_offset(3), Err(BoxRawPtrError::OutOfBounds));
+        assert_eq!(ptr.try_change_offset(2), Ok(()));
+        assert_eq!(ptr.try_write_ptr(9), Ok(()));
+        assert_eq!(ptr.try_access(), Ok(9));
+        assert_eq!(ptr.try_read_at(3), Err(BoxRawPtrError::OutOfBounds));
+        assert!(ptr.write_ptr(8).is_some());
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 8]);
+
+        let null: ConstRawPtr<i32> = ConstRawPtr::nullptr();
+        assert_eq!(null.try_access(), Err(BoxRawPtrError::Null));
+        assert_eq!(null.try_read_at(0), Err(BoxRawPtrError::Null));
+        assert_eq!(BoxRawPtrError::Misaligned.to_string(), "box_raw_ptr Err: Memory Not Aligned");
+    }
+
+    #[test]
+    fn check_bounds_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3, 4]);
+        ptr.offset = 3;
+        assert!(ptr.check_bounds());
+        /* One past the last element is out of bounds */
+        ptr.offset = 4;
+        assert!(!ptr.check_bounds());
+        assert!(ptr.access().is_none());
+
+        let mut ptr: ConstRawPtr<i32> = const_block(&[1, 2, 3, 4]);
+        ptr.change_offset(3).unwrap();
+        assert_eq!(ptr.access(), Some(4));
+        assert!(ptr.change_offset(1).is_none());
+    }
+
+    #[test]
+    fn cast_ptr_test() {
+        let mut ptr: MutRawPtr<u32> = mut_block(&[1, 2, 3, 4]);
+        /* The view shares the block, so only the original frees it */
+        {
+            let mut bytes = unsafe { ptr.cast_ptr::<u8>() }.unwrap();
+            assert_eq!(bytes.check_memory_length(), 16);
+            bytes.write_at(0, 0).unwrap();
+            bytes.write_at(1, 0).unwrap();
+            bytes.write_at(2, 0).unwrap();
+            bytes.write_at(3, 0).unwrap();
+        }
+        assert_eq!(mut_contents(&ptr), vec![0, 2, 3, 4]);
+        let halves = unsafe { const_block(&[1u32, 2]).cast_ptr::<u16>() }.map(|h| h.check_memory_length());
+        assert_eq!(halves, Some(4));
+        /* 6 bytes aren't a whole number of u32 */
+        let mut odd: MutRawPtr<u8> = mut_block(&[0u8; 6]);
+        assert!(unsafe { odd.cast_ptr::<u32>() }.is_none());
+        /* Neither is a 6 byte capacity behind a 4 byte length */
+        let mut short: MutRawPtr<u8> = mut_block(&[0u8; 6]);
+        short.memory_length = 4;
+        assert!(unsafe { short.cast_ptr::<u32>() }.is_none());
+    }
+
+    #[test]
+    fn offset_from_test() {
+        let ptr: ConstRawPtr<i32> = const_block(&[1, 2, 3, 4, 5]);
+        let cursor: ConstRawPtr<i32> = ConstRawPtr::new(ptr.ptr, 5, 3);
+        assert_eq!(unsafe { cursor.offset_from(&ptr) }, Some(3));
+        assert_eq!(unsafe { ptr.offset_from(&cursor) }, Some(-3));
+        assert_eq!(unsafe { ptr.offset_from(&ConstRawPtr::nullptr()) }, None);
+    }
+
+    #[test]
+    fn set_offset_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[10, 20, 30, 40]);
+        assert!(ptr.set_offset(3).is_some());
+        assert_eq!(ptr.access(), Some(40));
+        assert!(ptr.set_offset(1).is_some());
+        assert_eq!(ptr.access(), Some(20));
+        assert!(ptr.set_offset(4).is_none());
+        assert_eq!(ptr.check_offset(), 1);
+        let mut ptr: ConstRawPtr<i32> = const_block(&[5, 6]);
+        assert!(ptr.set_offset(1).is_some());
+        assert_eq!(ptr.access(), Some(6));
+    }
+
+    #[test]
+    fn reset_offset_test() {
+        let mut ptr: ConstRawPtr<i32> = const_block(&[1, 2, 3]);
+        ptr.change_offset(1).unwrap();
+        ptr.change_offset(1).unwrap();
+        ptr.reset_offset();
+        assert_eq!(ptr.check_offset(), 0);
+        assert_eq!(ptr.access(), Some(1));
+    }
+
+    #[test]
+    fn c_calloc_test() {
+        let ptr: MutRawPtr<u64> = unsafe { MutRawPtr::c_calloc(10, 0).unwrap() };
+        assert_eq!(mut_contents(&ptr), vec![0; 10]);
+        let ptr: ConstRawPtr<u64> = unsafe { ConstRawPtr::c_calloc(10, 9).unwrap() };
+        assert_eq!(ptr.access(), Some(0));
+        assert!(unsafe { ConstRawPtr::<u64>::c_calloc(0, 0) }.is_none());
+        assert!(unsafe { MutRawPtr::<u64>::c_calloc(3, 3) }.is_none());
+    }
+
+    #[test]
+    fn c_realloc_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3, 4]);
+        ptr.set_offset(3).unwrap();
+        unsafe { ptr.c_realloc(8).unwrap() };
+        assert_eq!(ptr.check_offset(), 3);
+        /* The grown tail is uninitialized until written */
+        for i in 4..8 {
+            ptr.write_at(i, 0).unwrap();
+        }
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 3, 4, 0, 0, 0, 0]);
+        /* Shrinking clamps the offset */
+        unsafe { ptr.c_realloc(2).unwrap() };
+        assert_eq!(mut_contents(&ptr), vec![1, 2]);
+        assert_eq!(ptr.check_offset(), 1);
+
+        /* Memory the pointer doesn't own can't be resized */
+        let mut data: [i32; 2] = [1, 2];
+        let mut borrowed: MutRawPtr<i32> = MutRawPtr::new(data.as_mut_ptr(), 2, 0);
+        assert!(unsafe { borrowed.c_realloc(4) }.is_none());
+        assert_eq!(borrowed.memory_length, 2);
+
+        let mut ptr: ConstRawPtr<i32> = const_block(&[5, 6, 7]);
+        unsafe { ptr.c_realloc(1).unwrap() };
+        assert_eq!(ptr.to_vec(), Some(vec![5]));
+    }
+
+    #[test]
+    fn c_malloc_aligned_test() {
+        let ptr: MutRawPtr<f32> = unsafe { MutRawPtr::c_malloc_aligned(vec![1.0; 16], 16, 0, 64).unwrap() };
+        assert_eq!(ptr.ptr as usize % 64, 0);
+        assert_eq!(ptr.layout().unwrap().align(), 64);
+        assert_eq!(mut_contents(&ptr), vec![1.0; 16]);
+        let ptr: ConstRawPtr<f32> = unsafe { ConstRawPtr::c_malloc_aligned(vec![2.0], 4, 0, 128).unwrap() };
+        assert_eq!(ptr.ptr as usize % 128, 0);
+        /* Alignments that aren't powers of two or are weaker than f32's are rejected */
+        assert!(unsafe { MutRawPtr::<f32>::c_malloc_aligned(vec![], 4, 0, 48) }.is_none());
+        assert!(unsafe { MutRawPtr::<f32>::c_malloc_aligned(vec![], 4, 0, 2) }.is_none());
+    }
+
+    #[test]
+    fn try_new_test() {
+        use super::error::BoxRawPtrError;
+
+        let mut data: [u32; 4] = [1, 2, 3, 4];
+        let misaligned: *mut u32 = (data.as_mut_ptr() as usize + 1) as *mut u32;
+        assert_eq!(MutRawPtr::try_new(misaligned, 2, 0).err(), Some(BoxRawPtrError::Misaligned));
+        assert_eq!(MutRawPtr::try_new(data.as_mut_ptr(), 4, 4).err(), Some(BoxRawPtrError::OutOfBounds));
+        let ptr: ConstRawPtr<u32> = ConstRawPtr::try_new(data.as_ptr(), 4, 3).unwrap();
+        assert_eq!(ptr.access(), Some(4));
+    }
+
+    #[test]
+    #[should_panic(expected = "box_raw_ptr Err: Offset Is Not Within Bounds")]
+    fn new_out_of_bounds_test() {
+        let data: [u32; 2] = [1, 2];
+        let _ = ConstRawPtr::new(data.as_ptr(), 2, 2);
+    }
+
+    #[test]
+    fn debug_test() {
+        assert_eq!(format!("{:?}", ConstRawPtr::<i32>::nullptr()), "ConstRawPtr { ptr: null, len: 0, offset: 0 }");
+        let mut ptr: MutRawPtr<i32> = mut_block(&[7, 8]);
+        ptr.change_offset(1).unwrap();
+        let expected: String = format!("MutRawPtr {{ ptr: {:#x}, len: 2, offset: 1, value: 8 }}", ptr.ptr as usize);
+        assert_eq!(format!("{:?}", ptr), expected);
+    }
+
+    #[test]
+    fn address_format_test() {
+        let ptr: ConstRawPtr<i32> = const_block(&[1]);
+        let address: usize = ptr.ptr as usize;
+        assert_eq!(format!("{:p}", ptr), format!("{:p}", ptr.ptr));
+        assert_eq!(format!("{:x}", ptr), format!("{:x}", address));
+        assert_eq!(format!("{:#x}", ptr), format!("{:#x}", address));
+        assert_eq!(format!("{:X}", MutRawPtr::<i32>::nullptr()), "0");
+    }
+
+    #[test]
+    fn partial_eq_test() {
+        let a: ConstRawPtr<i32> = const_block(&[1, 2]);
+        let mut b: ConstRawPtr<i32> = const_block(&[2, 1]);
+        assert!(a != b);
+        b.change_offset(1).unwrap();
+        assert_eq!(a, b);
+        assert!(!a.ptr_eq(&b));
+        assert!(a.fork().ptr_eq(&a));
+        assert_eq!(MutRawPtr::<i32>::nullptr(), MutRawPtr::<i32>::nullptr());
+        assert!(mut_block(&[0]) != MutRawPtr::nullptr());
+
+        /* An invalid pointer is still equal to itself */
+        let mut past_end: MutRawPtr<i32> = mut_block(&[1, 2]);
+        past_end.offset = 2;
+        assert!(past_end.access().is_none());
+        let same: &MutRawPtr<i32> = &past_end;
+        assert!(past_end == *same);
+        let mut other_end: MutRawPtr<i32> = mut_block(&[1, 2]);
+        other_end.offset = 2;
+        assert!(past_end != other_end);
+        assert!(mut_block(&[1, 2]) != past_end);
+    }
+
+    #[test]
+    fn swap_test() {
+        let mut a: MutRawPtr<i32> = mut_block(&[1, 2, 3]);
+        assert!(a.swap_at(0, 2).is_some());
+        assert!(a.swap_at(0, 3).is_none());
+        assert_eq!(mut_contents(&a), vec![3, 2, 1]);
+
+        let mut b: MutRawPtr<i32> = mut_block(&[7, 8]);
+        a.change_offset(1).unwrap();
+        b.change_offset(1).unwrap();
+        assert!(a.swap(&mut b).is_some());
+        assert_eq!(mut_contents(&a), vec![3, 8, 1]);
+        assert_eq!(mut_contents(&b), vec![7, 2]);
+        assert!(a.swap(&mut MutRawPtr::nullptr()).is_none());
+    }
+
+    #[test]
+    fn sort_block_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[3, 1, 4, 1, 5]);
+        assert!(ptr.sort_block().is_some());
+        assert_eq!(mut_contents(&ptr), vec![1, 1, 3, 4, 5]);
+        assert!(ptr.sort_block_by(|a, b| b.cmp(a)).is_some());
+        assert_eq!(mut_contents(&ptr), vec![5, 4, 3, 1, 1]);
+        assert!(ptr.reverse_block().is_some());
+        assert_eq!(mut_contents(&ptr), vec![1, 1, 3, 4, 5]);
+        assert!(MutRawPtr::<i32>::nullptr().sort_block().is_none());
+    }
+
+    #[test]
+    fn from_ref_test() {
+        let value: i32 = 5;
+        let ptr = ConstRawPtr::from_ref(&value);
+        assert_eq!(ptr.access(), Some(5));
+        drop(ptr);
+
+        let mut value: i32 = 5;
+        let mut ptr = MutRawPtr::from_mut(&mut value);
+        ptr.write_ptr(6).unwrap();
+        /* Dropping the pointer must not free the stack value */
+        drop(ptr);
+        assert_eq!(value, 6);
+    }
+
+    #[test]
+    fn as_non_null_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2]);
+        ptr.change_offset(1).unwrap();
+        let non_null: std::ptr::NonNull<i32> = ptr.as_non_null().unwrap();
+        assert_eq!(unsafe { *non_null.as_ptr() }, 2);
+        assert!(ConstRawPtr::<i32>::nullptr().as_non_null().is_none());
+    }
+
+    #[test]
+    fn raw_parts_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3]);
+        ptr.change_offset(2).unwrap();
+        let (raw, memory_length, offset) = ptr.into_raw_parts();
+        assert_eq!((memory_length, offset), (3, 2));
+        /* The rebuilt pointer owns and frees the block */
+        let ptr: MutRawPtr<i32> = unsafe { MutRawPtr::from_raw_parts(raw, memory_length, offset) };
+        assert_eq!(ptr.access(), Some(3));
+        assert_eq!(mut_contents(&ptr), vec![1, 2, 3]);
+
+        let (raw, memory_length, offset) = const_block(&[4]).into_raw_parts();
+        let ptr: ConstRawPtr<i32> = unsafe { ConstRawPtr::from_raw_parts(raw, memory_length, offset) };
+        assert_eq!(ptr.access(), Some(4));
+    }
+
+    #[test]
+    fn get_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3]);
+        *ptr.get_mut(2).unwrap() = 9;
+        assert_eq!(ptr.read_at(2), Some(9));
+        assert_eq!(ptr.get(0), Some(&1));
+        assert!(ptr.get_mut(3).is_none());
+        assert_eq!(const_block(&[4, 5]).get(1), Some(&5));
+        assert!(ConstRawPtr::<i32>::nullptr().get(0).is_none());
+    }
+
+    #[test]
+    fn index_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3]);
+        ptr[1] += 10;
+        assert_eq!(ptr[1], 12);
+        assert_eq!(const_block(&[4, 5])[0], 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "box_raw_ptr Err: Index Out Of Bounds")]
+    fn index_out_of_bounds_test() {
+        let ptr: ConstRawPtr<i32> = const_block(&[1, 2, 3]);
+        let _ = ptr[3];
+    }
+
+    #[test]
+    fn copy_from_test() {
+        let mut src: ConstRawPtr<i32> = const_block(&[1, 2, 3, 4]);
+        let mut dst: MutRawPtr<i32> = mut_block(&[0; 4]);
+        src.change_offset(1).unwrap();
+        dst.change_offset(2).unwrap();
+        assert!(dst.copy_from(&src, 2).is_some());
+        assert_eq!(mut_contents(&dst), vec![0, 0, 2, 3]);
+        /* Only 2 elements remain after the destination offset */
+        assert!(dst.copy_from(&src, 3).is_none());
+
+        /* The source may be a view of the destination block */
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3, 4, 5]);
+        let view: ConstRawPtr<i32> = ptr.as_const();
+        ptr.change_offset(1).unwrap();
+        assert!(ptr.copy_from(&view, 4).is_some());
+        assert_eq!(mut_contents(&ptr), vec![1, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn copy_within_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3, 4, 5]);
+        /* Overlapping ranges */
+        assert!(ptr.copy_within(0, 1, 3).is_some());
+        assert_eq!(mut_contents(&ptr), vec![1, 1, 2, 3, 5]);
+        /* Non-overlapping ranges */
+        assert!(ptr.copy_within(3, 0, 2).is_some());
+        assert_eq!(mut_contents(&ptr), vec![3, 5, 2, 3, 5]);
+        assert!(ptr.copy_within(3, 0, 3).is_none());
+    }
+
+    #[test]
+    fn remaining_first_last_test() {
+        let mut ptr: ConstRawPtr<i32> = const_block(&[4, 5, 6]);
+        assert_eq!(ptr.remaining(), 3);
+        ptr.change_offset(2).unwrap();
+        assert_eq!(ptr.remaining(), 1);
+        assert_eq!(ptr.first(), Some(4));
+        assert_eq!(ptr.last(), Some(6));
+        /* The cursor is not moved */
+        assert_eq!(ptr.check_offset(), 2);
+
+        let mut_ptr: MutRawPtr<i32> = mut_block(&[7, 8]);
+        assert_eq!((mut_ptr.first(), mut_ptr.last()), (Some(7), Some(8)));
+
+        let null: ConstRawPtr<i32> = ConstRawPtr::nullptr();
+        assert_eq!((null.remaining(), null.first(), null.last()), (0, None, None));
+    }
+
+    #[test]
+    fn chunks_windows_test() {
+        let ptr: ConstRawPtr<u8> = const_block(&[1, 2, 3, 4, 5]);
+        let chunks: Vec<&[u8]> = ptr.chunks(2).collect();
+        assert_eq!(chunks, vec![&[1, 2][..], &[3, 4][..], &[5][..]]);
+        let windows: Vec<&[u8]> = ptr.windows(4).collect();
+        assert_eq!(windows, vec![&[1, 2, 3, 4][..], &[2, 3, 4, 5][..]]);
+
+        let mut_ptr: MutRawPtr<u8> = mut_block(&[1, 2, 3]);
+        assert_eq!(mut_ptr.chunks(3).count(), 1);
+        assert_eq!(mut_ptr.windows(4).count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn chunks_zero_size_test() {
+        let ptr: ConstRawPtr<u8> = const_block(&[1, 2, 3]);
+        let _ = ptr.chunks(0);
+    }
+
+    #[test]
+    fn split_at_test() {
+        let ptr: ConstRawPtr<i32> = const_block(&[1, 2, 3, 4, 5]);
+        {
+            let (left, right) = ptr.split_at(2).unwrap();
+            assert_eq!(left.to_vec().unwrap(), vec![1, 2]);
+            assert_eq!(right.to_vec().unwrap(), vec![3, 4, 5]);
+            assert_eq!(right.first(), Some(3));
+            /* Views are dropped here without freeing the allocation */
+        }
+        assert_eq!(ptr.to_vec().unwrap(), vec![1, 2, 3, 4, 5]);
+
+        let (left, right) = ptr.split_at(5).unwrap();
+        assert_eq!((left.check_memory_length(), right.check_memory_length()), (5, 0));
+        assert!(ptr.split_at(6).is_none());
+    }
+
+    #[test]
+    fn fill_with_test() {
+        let mut ptr: MutRawPtr<i32> = mut_block(&[0; 5]);
+        ptr.change_offset(3).unwrap();
+        assert!(ptr.fill_with(|i| i as i32 * 2).is_some());
+        assert_eq!(mut_contents(&ptr), vec![0, 2, 4, 6, 8]);
+        assert_eq!(ptr.check_offset(), 3);
+        assert!(MutRawPtr::<i32>::nullptr().fill_with(|i| i as i32).is_none());
+    }
+
+    #[test]
+    fn change_memory_length_boundary_test() {
+        let mut ptr: ConstRawPtr<i32> = const_block(&[1, 2, 3, 4]);
+        ptr.change_offset(2).unwrap();
+        unsafe {
+            /* offset == memory_length would leave the cursor out of bounds */
+            assert!(ptr.change_memory_length(2).is_none());
+            assert!(ptr.change_memory_length(0).is_none());
+            assert!(ptr.change_memory_length(3).is_some());
+        }
+        assert!(ptr.check_bounds());
+
+        let mut mut_ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3, 4]);
+        mut_ptr.change_offset(2).unwrap();
+        assert!(mut_ptr.change_memory_length(2).is_none());
+        assert!(mut_ptr.change_memory_length(0).is_none());
+        assert!(mut_ptr.change_memory_length(3).is_some());
+        assert!(mut_ptr.check_bounds());
+    }
+
+    #[test]
+    fn cursor_test() {
+        let mut cursor: ConstRawPtr<u8> = const_block(&[1, 2, 3]);
+        assert_eq!(cursor.peek(2), Some(3));
+        assert!(cursor.peek(3).is_none());
+        let mut read: Vec<u8> = Vec::new();
+        while let Some(byte) = cursor.advance() {
+            read.push(byte);
+        }
+        assert_eq!(read, vec![1, 2, 3]);
+        /* The cursor rests one past the end */
+        assert!(cursor.current().is_none());
+        assert_eq!(cursor.retreat(), Some(3));
+        assert_eq!(cursor.current(), Some(3));
+        cursor.reset_offset();
+        assert!(cursor.retreat().is_none());
+
+        let mut mut_cursor: MutRawPtr<u8> = mut_block(&[4, 5]);
+        assert_eq!(mut_cursor.advance(), Some(4));
+        assert_eq!(mut_cursor.current(), Some(5));
+        assert_eq!(mut_cursor.peek(1), None);
+        assert_eq!(mut_cursor.retreat(), Some(4));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_test() {
+        extern crate serde_json;
+
+        let data: Vec<u32> = vec![10, 20, 30];
+        let mut ptr: MutRawPtr<u32> = mut_block(&data);
+        ptr.change_offset(1).unwrap();
+        let json: String = serde_json::to_string(&ptr).unwrap();
+        assert_eq!(json, r#"{"memory_length":3,"offset":1,"data":[10,20,30]}"#);
+
+        let decoded: MutRawPtr<u32> = serde_json::from_str(&json).unwrap();
+        /* A new allocation holding the same values */
+        assert!(!decoded.ptr_eq(&ptr));
+        assert_eq!(decoded, ptr);
+        assert_eq!(decoded.check_offset(), 1);
+
+        let decoded: ConstRawPtr<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.to_vec().unwrap(), data);
+
+        /* The data must match the memory length */
+        assert!(serde_json::from_str::<ConstRawPtr<u32>>(r#"{"memory_length":4,"offset":0,"data":[1]}"#).is_err());
+        assert!(serde_json::to_string(&ConstRawPtr::<u32>::nullptr()).is_err());
+    }
+
+    #[test]
+    fn as_bytes_reinterpret_test() {
+        let ptr: ConstRawPtr<u16> = const_block(&[0x0102, 0x0304]);
+        let bytes: &[u8] = unsafe { ptr.as_bytes() }.unwrap();
+        assert_eq!(bytes.len(), 4);
+        assert_eq!(bytes, &[0x0102u16.to_ne_bytes(), 0x0304u16.to_ne_bytes()].concat()[..]);
+
+        let words = unsafe { ptr.reinterpret::<u32>() }.unwrap();
+        assert_eq!(words.check_memory_length(), 1);
+        /* The view doesn't free the allocation when dropped */
+        drop(words);
+        assert_eq!(ptr.read_at(1), Some(0x0304));
+
+        let mut mut_ptr: MutRawPtr<u16> = mut_block(&[0, 0]);
+        unsafe { mut_ptr.as_bytes_mut() }.unwrap().copy_from_slice(&[0xFF; 4]);
+        assert_eq!(mut_contents(&mut_ptr), vec![0xFFFF, 0xFFFF]);
+    }
+
+    #[test]
+    fn cstr_helpers_test() {
+        let mut c_str: ConstRawPtr<u8> = const_block(b"box\0raw\0");
+        assert_eq!(c_str.c_strlen(), Some(3));
+        assert_eq!(c_str.as_cstr().unwrap().to_bytes(), b"box");
+        c_str.change_offset(4).unwrap();
+        assert_eq!(c_str.to_string_lossy_owned().unwrap(), "raw");
+
+        /* No terminator within the memory length */
+        let unterminated: ConstRawPtr<u8> = const_block(b"ptr");
+        assert!(unterminated.c_strlen().is_none());
+        assert!(unterminated.as_cstr().is_none());
+
+        let invalid: ConstRawPtr<u8> = const_block(b"\xFFok\0");
+        assert_eq!(invalid.to_string_lossy_owned().unwrap(), "\u{FFFD}ok");
+    }
+
+    #[test]
+    fn position_contains_test() {
+        let mut ptr: ConstRawPtr<u8> = const_block(&[7, 0xFF, 3, 0xFF]);
+        ptr.change_offset(2).unwrap();
+        /* Indices are absolute, not relative to the offset */
+        assert_eq!(ptr.position(0xFF), Some(1));
+        assert!(ptr.position(9).is_none());
+        assert!(ptr.contains(7));
+
+        let mut_ptr: MutRawPtr<u8> = mut_block(&[1, 2]);
+        assert_eq!(mut_ptr.position(2), Some(1));
+        assert!(!mut_ptr.contains(3));
+        assert!(!ConstRawPtr::<u8>::nullptr().contains(0));
+    }
+
+    #[test]
+    fn checked_offset_test() {
+        let mut ptr: ConstRawPtr<i32> = const_block(&[1, 2, 3]);
+        ptr.change_offset(1).unwrap();
+        assert!(ptr.checked_offset(isize::MAX).is_none());
+        assert!(ptr.checked_offset(isize::MIN).is_none());
+        assert!(ptr.change_offset(isize::MAX).is_none());
+        assert_eq!(ptr.check_offset(), 1);
+        assert!(ptr.checked_offset(1).is_some());
+        assert_eq!(ptr.access(), Some(3));
+
+        let mut mut_ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3]);
+        mut_ptr.change_offset(2).unwrap();
+        assert!(mut_ptr.checked_offset(isize::MAX).is_none());
+        assert!(mut_ptr.checked_offset(-2).is_some());
+        assert_eq!(mut_ptr.access(), Some(1));
+    }
+
+    #[test]
+    fn saturating_wrapping_offset_test() {
+        let mut ptr: ConstRawPtr<i32> = const_block(&[1, 2, 3, 4]);
+        ptr.saturating_offset(10);
+        assert_eq!(ptr.check_offset(), 3);
+        ptr.saturating_offset(isize::MIN);
+        assert_eq!(ptr.check_offset(), 0);
+        ptr.wrapping_offset(-1);
+        assert_eq!(ptr.access(), Some(4));
+        ptr.wrapping_offset(isize::MAX);
+        assert_eq!(ptr.check_offset(), (3 + isize::MAX as usize) % 4);
+
+        let mut mut_ptr: MutRawPtr<i32> = mut_block(&[1, 2, 3]);
+        mut_ptr.wrapping_offset(7);
+        assert_eq!(mut_ptr.access(), Some(2));
+        mut_ptr.saturating_offset(-5);
+        assert_eq!(mut_ptr.access(), Some(1));
+
+        let mut null: MutRawPtr<i32> = MutRawPtr::nullptr();
+        null.wrapping_offset(1);
+        null.saturating_offset(1);
+        assert_eq!(null.check_offset(), 0);
+    }
+
+    #[test]
+    fn swap_ptr_test() {
+        use std::sync::atomic::Ordering;
+
+        let mut ptr: MutRawPtr<i32> = mut_block(&[1, 2]);
+        let replacement: MutRawPtr<i32> = mut_block(&[3, 4]);
+        let (new, _, _) = replacement.into_raw_parts();
+
+        let old: *mut i32 = unsafe { ptr.swap_ptr(new, Ordering::AcqRel) };
+        assert_eq!(mut_contents(&ptr), vec![3, 4]);
+        /* The previous allocation is handed back to the caller */
+        let old: MutRawPtr<i32> = unsafe { MutRawPtr::from_raw_parts(old, 2, 0) };
+        assert_eq!(mut_contents(&old), vec![1, 2]);
+
+        let (old_ptr, _, _) = old.into_raw_parts();
+        unsafe {
+            assert_eq!(ptr.compare_exchange_ptr(old_ptr, old_ptr, Ordering::AcqRel, Ordering::Acquire), Err(new));
+            assert_eq!(ptr.compare_exchange_ptr(new, old_ptr, Ordering::AcqRel, Ordering::Acquire), Ok(new));
+        }
+        assert_eq!(mut_contents(&ptr), vec![1, 2]);
+        drop(unsafe { MutRawPtr::from_raw_parts(new, 2, 0) });
+    }
+
+    #[test]
+    fn mut_raw_ptr_owned_test() {
+        use super::owned_raw_ptr::MutRawPtrOwned;
+        use std::sync::Arc;
+
+        let mut names: MutRawPtrOwned<String> = MutRawPtrOwned::from_vec(vec![String::from("box"), String::from("raw")], 1).unwrap();
+        assert_eq!(names.access().unwrap(), "raw");
+        names.access_mut().unwrap().push_str("_ptr");
+        names.change_offset(-1).unwrap();
+        names.write_ptr(String::from("c")).unwrap();
+        assert_eq!(names.into_vec(), vec![String::from("c"), String::from("raw_ptr")]);
+        assert!(MutRawPtrOwned::<String>::from_vec(Vec::new(), 0).is_none());
+
+        /* Overwritten elements and the remaining elements on drop are dropped exactly once */
+        let counter: Arc<()> = Arc::new(());
+        let mut shared: MutRawPtrOwned<Arc<()>> = MutRawPtrOwned::from_vec(vec![counter.clone(), counter.clone()], 0).unwrap();
+        assert_eq!(Arc::strong_count(&counter), 3);
+        shared.write_ptr(Arc::new(())).unwrap();
+        assert_eq!(Arc::strong_count(&counter), 2);
+        drop(shared);
+        assert_eq!(Arc::strong_count(&counter), 1);
     }
 }